@@ -0,0 +1,192 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::ops::ControlFlow;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Mutex, MutexGuard, OnceLock, PoisonError};
+use std::thread;
+use std::time::Duration;
+
+use crate::render::{Canvas, Color, Format, Image};
+
+/// Receives the intermediate states of a simulation, see
+/// [`Solution::animate`](crate::Solution::animate). Returning
+/// `ControlFlow::Break` asks the simulation to stop early.
+pub type Frames<'a> = dyn FnMut(&Canvas) -> ControlFlow<()> + 'a;
+
+/// Plays frames in the terminal using ANSI colours, two grid rows per line.
+///
+/// Pressing Enter while playing pauses. While paused Enter shows the next
+/// frame, `c` continues playing and `q` stops the animation.
+pub struct Player {
+    pub delay: Duration,
+    pub paused: bool,
+    /// Terminal output can be turned off when only dumping frames.
+    pub display: bool,
+    dump: Option<(PathBuf, Format, usize)>,
+    frames: usize,
+}
+
+impl Player {
+    pub fn new(delay: Duration) -> Player {
+        Player {
+            delay,
+            paused: false,
+            display: true,
+            dump: None,
+            frames: 0,
+        }
+    }
+
+    /// Additionally writes every frame to `dir/frameNNNNN.<ext>` with `scale`
+    /// pixels per tile.
+    pub fn dump(mut self, dir: impl Into<PathBuf>, format: Format, scale: usize) -> Player {
+        self.dump = Some((dir.into(), format, scale));
+        self
+    }
+
+    /// Number of frames shown so far.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    pub fn show(&mut self, canvas: &Canvas) -> ControlFlow<()> {
+        if let Some((dir, format, scale)) = &self.dump {
+            let path = dir.join(format!("frame{:05}.{}", self.frames, format.extension()));
+            let written = fs::create_dir_all(dir)
+                .and_then(|_| fs::write(&path, canvas.encode(*format, *scale)));
+
+            if let Err(err) = written {
+                eprintln!("error: cannot write {}: {err}", path.display());
+                return ControlFlow::Break(());
+            }
+        }
+        self.frames += 1;
+
+        if !self.display {
+            return ControlFlow::Continue(());
+        }
+
+        let mut out = io::stdout().lock();
+        let clear = if self.frames == 1 { "\x1b[2J" } else { "" };
+        let frame = to_ansi(&canvas.to_image(1));
+        let status = if self.paused {
+            "paused: Enter = next frame, c = continue, q = quit"
+        } else {
+            "playing: Enter = pause"
+        };
+
+        writeln!(
+            out,
+            "{clear}\x1b[H{frame}frame {} - {status}\x1b[K",
+            self.frames
+        )
+        .and_then(|_| out.flush())
+        .ok();
+
+        self.wait()
+    }
+
+    fn wait(&mut self) -> ControlFlow<()> {
+        let keys = keys();
+
+        if !self.paused {
+            thread::sleep(self.delay);
+
+            match keys.try_recv().as_deref() {
+                Ok("q") => return ControlFlow::Break(()),
+                Ok(_) => self.paused = true,
+                Err(_) => (),
+            }
+            return ControlFlow::Continue(());
+        }
+
+        match keys.recv().as_deref() {
+            Ok("q") | Err(_) => ControlFlow::Break(()),
+            Ok("c") => {
+                self.paused = false;
+                ControlFlow::Continue(())
+            }
+            Ok(_) => ControlFlow::Continue(()),
+        }
+    }
+}
+
+/// Lines typed on stdin. One thread reads them for all players of the
+/// process, as a reader per player would keep stdin locked after its
+/// animation ended and swallow the first key meant for the next one.
+fn keys() -> MutexGuard<'static, Receiver<String>> {
+    static KEYS: OnceLock<Mutex<Receiver<String>>> = OnceLock::new();
+
+    KEYS.get_or_init(|| {
+        let (send, keys) = mpsc::channel();
+        thread::spawn(move || {
+            for line in io::stdin().lock().lines() {
+                let Ok(line) = line else { break };
+                if send.send(line.trim().to_string()).is_err() {
+                    break;
+                }
+            }
+        });
+        Mutex::new(keys)
+    })
+    .lock()
+    .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Draws two pixel rows per line with the upper half block, the foreground
+/// colour being the upper and the background colour the lower pixel.
+fn to_ansi(image: &Image) -> String {
+    let mut ansi = String::new();
+
+    for y in (0..image.height).step_by(2) {
+        let mut last = None;
+
+        for x in 0..image.width {
+            let top = image.pixels[y * image.width + x];
+            let bottom = if y + 1 < image.height {
+                image.pixels[(y + 1) * image.width + x]
+            } else {
+                Color::BLACK
+            };
+
+            if last != Some((top, bottom)) {
+                write!(
+                    ansi,
+                    "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m",
+                    top.r, top.g, top.b, bottom.r, bottom.g, bottom.b
+                )
+                .unwrap();
+                last = Some((top, bottom));
+            }
+            ansi.push('▀');
+        }
+
+        ansi.push_str("\x1b[0m\x1b[K\n");
+    }
+
+    ansi
+}
+
+#[test]
+fn test_animate() {
+    let image = Image {
+        width: 2,
+        height: 3,
+        pixels: vec![
+            Color::WHITE,
+            Color::WHITE,
+            Color::RED,
+            Color::RED,
+            Color::BLUE,
+            Color::WHITE,
+        ],
+    };
+
+    let ansi = to_ansi(&image);
+    assert_eq!(ansi.lines().count(), 2);
+    assert_eq!(ansi.matches('▀').count(), 4);
+    assert!(ansi.starts_with("\x1b[38;2;255;255;255m\x1b[48;2;220;50;47m▀▀"));
+    assert!(ansi.contains("\x1b[38;2;38;139;210m\x1b[48;2;0;0;0m▀"));
+}
@@ -0,0 +1,148 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::{Day, ParseError, Part};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(samples: &mut [Duration]) -> Option<Stats> {
+        samples.sort();
+
+        Some(Stats {
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub day: u32,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl Report {
+    fn phases(&self) -> impl Iterator<Item = (&'static str, Stats)> + '_ {
+        [
+            ("parse", Some(self.parse)),
+            ("part1", self.part1),
+            ("part2", self.part2),
+        ]
+        .into_iter()
+        .filter_map(|(phase, stats)| Some((phase, stats?)))
+    }
+}
+
+/// Solves the selected parts of `day` `runs` times and collects the timings of
+/// every phase.
+pub fn bench(day: &Day, input: &str, part: Part, runs: usize) -> Result<Report, ParseError> {
+    let mut parse = vec![];
+    let mut part1 = vec![];
+    let mut part2 = vec![];
+
+    for _ in 0..runs.max(1) {
+        let (_, timings) = day.time(input, part)?;
+        parse.push(timings.parse);
+        part1.extend(timings.part1);
+        part2.extend(timings.part2);
+    }
+
+    Ok(Report {
+        day: day.day,
+        runs: runs.max(1),
+        parse: Stats::from_samples(&mut parse).unwrap(),
+        part1: Stats::from_samples(&mut part1),
+        part2: Stats::from_samples(&mut part2),
+    })
+}
+
+pub fn to_table(reports: &[Report]) -> String {
+    let mut table = String::new();
+
+    writeln!(
+        table,
+        "day  phase  {:>12} {:>12} {:>12}",
+        "min", "median", "max"
+    )
+    .unwrap();
+
+    for report in reports {
+        for (phase, stats) in report.phases() {
+            writeln!(
+                table,
+                "{:02}   {phase}  {:>12.2?} {:>12.2?} {:>12.2?}",
+                report.day, stats.min, stats.median, stats.max
+            )
+            .unwrap();
+        }
+    }
+
+    table
+}
+
+pub fn to_csv(reports: &[Report]) -> String {
+    let mut csv = String::from("day,phase,runs,min_ns,median_ns,max_ns\n");
+
+    for report in reports {
+        for (phase, stats) in report.phases() {
+            writeln!(
+                csv,
+                "{},{phase},{},{},{},{}",
+                report.day,
+                report.runs,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.max.as_nanos()
+            )
+            .unwrap();
+        }
+    }
+
+    csv
+}
+
+pub fn to_json(reports: &[Report]) -> String {
+    let mut entries = vec![];
+
+    for report in reports {
+        for (phase, stats) in report.phases() {
+            entries.push(format!(
+                "  {{\"day\": {}, \"phase\": \"{phase}\", \"runs\": {}, \
+                 \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                report.day,
+                report.runs,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.max.as_nanos()
+            ));
+        }
+    }
+
+    format!("[\n{}\n]\n", entries.join(",\n"))
+}
+
+#[test]
+fn test_stats() {
+    let ms = Duration::from_millis;
+    let mut samples = [ms(5), ms(1), ms(3), ms(9), ms(4)];
+
+    assert_eq!(
+        Stats::from_samples(&mut samples),
+        Some(Stats {
+            min: ms(1),
+            median: ms(4),
+            max: ms(9)
+        })
+    );
+    assert_eq!(Stats::from_samples(&mut []), None);
+}
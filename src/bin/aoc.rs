@@ -0,0 +1,615 @@
+use std::env;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::process;
+use std::time::Duration;
+
+use aoc_2023::animate::Player;
+use aoc_2023::output::{self, ErrorKind};
+use aoc_2023::render::Format;
+use aoc_2023::verify::{self, Status};
+use aoc_2023::{bench, cancel, Day, Part};
+
+const USAGE: &str = "\
+usage: aoc [DAY | FIRST-LAST] [--part 1|2] [INPUTS] [--all-inputs]
+           [--format text|json|tsv] [--render DIR] [--image png|svg|ppm]
+           [--seed N] [--stats]
+       aoc bench [DAY | FIRST-LAST] [--part 1|2] [INPUTS] [--runs N]
+                 [--json PATH] [--csv PATH]
+       aoc verify [DAY | FIRST-LAST] [--part 1|2] [--user NAME]
+                  [--answers PATH] [--timeout SECS]
+       aoc animate [DAY | FIRST-LAST] [INPUTS] [--delay MS] [--step]
+                   [--dump DIR] [--image png|svg|ppm] [--no-display]
+       aoc validate [DAY | FIRST-LAST] [INPUTS] [--all-inputs]
+       aoc generate DAY [--seed N] [--size N] [--output PATH]
+
+INPUTS is --input PATH or --user NAME. Every mode except generate also takes
+--timeout SECS.
+
+Runs the solutions of all days if no day is given. --input is only allowed
+when a single day is selected, otherwise input/inputNN.txt is used. Use
+--input - to read the input from stdin. --user NAME reads the inputs of a
+team member from input/NAME/inputNN.txt instead, --all-inputs runs every
+input registered in an answers file.
+
+A panic in a day is reported and the remaining days still run. --timeout
+stops a day after SECS seconds, but only the long searches of days 23 and 25
+can be stopped early.
+
+--seed sets the seed of the days that use randomness (day 25), the same seed
+always takes the same steps. --stats prints statistics of the run, like the
+number of Karger trials day 25 needed.
+
+--format json or tsv prints one record per part with the day, part, input,
+answer, answer type and time in nanoseconds, or an error of kind input, parse
+or panic. A panicking part does not stop the other parts and days.
+
+--render additionally saves a picture of every selected day that is solved on
+a grid to DIR/dayNN.png (or the format given by --image).
+
+bench solves every day --runs times (default 10) and prints min, median and
+max of the parse, part1 and part2 phases. --json and --csv additionally write
+the report to a file.
+
+verify solves every input listed in the answers files, input/answers.txt and
+input/NAME/answers.txt of every user, and compares the results with the
+recorded answers. --user only checks the inputs of one user, --answers reads
+a single answers file instead.
+
+animate plays the simulation of days 14, 16, 21 and 22 in the terminal, one
+frame every --delay milliseconds (default 50). Press Enter to pause, then
+Enter to step, c to continue or q to quit; --step starts paused. --dump
+additionally writes every frame as an image to DIR/dayNN/.
+
+validate checks the assumptions that the solutions make about the official
+inputs, e.g. that S is in the centre of the map of day 21, and reports the
+ones that the input breaks.
+
+generate prints a random input for DAY, or writes it to --output. The same
+--seed (default 0) always gives the same input, --size (default 100) is the
+number of lines, tiles per side or items, see the generator of each day.";
+
+/// Pixels per grid tile of rendered images.
+const SCALE: usize = 4;
+
+enum Mode {
+    Run {
+        format: output::Format,
+        render: Option<String>,
+        image: Format,
+    },
+    Bench {
+        runs: usize,
+        json: Option<String>,
+        csv: Option<String>,
+    },
+    Verify {
+        answers: Option<String>,
+    },
+    Animate {
+        delay: Duration,
+        step: bool,
+        dump: Option<String>,
+        image: Format,
+        display: bool,
+    },
+    Validate,
+    Generate {
+        seed: u64,
+        size: usize,
+        output: Option<String>,
+    },
+}
+
+struct Args {
+    mode: Mode,
+    days: RangeInclusive<u32>,
+    part: Part,
+    input: Option<String>,
+    user: Option<String>,
+    all_inputs: bool,
+    timeout: Option<Duration>,
+    seed: Option<u64>,
+    stats: bool,
+}
+
+fn parse_days(arg: &str) -> Option<RangeInclusive<u32>> {
+    let (first, last) = match arg.split_once('-') {
+        Some((first, last)) => (first.parse().ok()?, last.parse().ok()?),
+        None => {
+            let day = arg.parse().ok()?;
+            (day, day)
+        }
+    };
+
+    if 1 <= first && first <= last && last <= 25 {
+        Some(first..=last)
+    } else {
+        None
+    }
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        mode: Mode::Run {
+            format: output::Format::Text,
+            render: None,
+            image: Format::default(),
+        },
+        days: 1..=25,
+        part: Part::Both,
+        input: None,
+        user: None,
+        all_inputs: false,
+        timeout: None,
+        seed: None,
+        stats: false,
+    };
+    let mut argv = env::args().skip(1).peekable();
+
+    match argv.peek().map(String::as_str) {
+        Some("bench") => {
+            args.mode = Mode::Bench {
+                runs: 10,
+                json: None,
+                csv: None,
+            };
+            argv.next();
+        }
+        Some("verify") => {
+            args.mode = Mode::Verify { answers: None };
+            argv.next();
+        }
+        Some("animate") => {
+            args.mode = Mode::Animate {
+                delay: Duration::from_millis(50),
+                step: false,
+                dump: None,
+                image: Format::default(),
+                display: true,
+            };
+            argv.next();
+        }
+        Some("validate") => {
+            args.mode = Mode::Validate;
+            argv.next();
+        }
+        Some("generate") => {
+            args.mode = Mode::Generate {
+                seed: 0,
+                size: 100,
+                output: None,
+            };
+            argv.next();
+        }
+        _ => (),
+    }
+
+    while let Some(arg) = argv.next() {
+        match (arg.as_str(), &mut args.mode) {
+            ("--part", _) => {
+                args.part = match argv.next().as_deref() {
+                    Some("1") => Part::One,
+                    Some("2") => Part::Two,
+                    _ => return Err("--part expects 1 or 2".to_string()),
+                }
+            }
+            ("--input", _) => {
+                let Some(path) = argv.next() else {
+                    return Err("--input expects a path".to_string());
+                };
+                args.input = Some(path);
+            }
+            ("--timeout", _) => {
+                let timeout = argv
+                    .next()
+                    .and_then(|secs| secs.parse().ok())
+                    .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                    .filter(|timeout| !timeout.is_zero())
+                    .ok_or("--timeout expects a positive number of seconds")?;
+                args.timeout = Some(timeout);
+            }
+            ("--user", _) => {
+                args.user = Some(argv.next().ok_or("--user expects a name")?);
+            }
+            ("--all-inputs", Mode::Run { .. } | Mode::Validate) => args.all_inputs = true,
+            ("--runs", Mode::Bench { runs, .. }) => {
+                *runs = argv
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .filter(|&n| n > 0)
+                    .ok_or("--runs expects a positive number")?;
+            }
+            ("--json", Mode::Bench { json, .. }) => {
+                *json = Some(argv.next().ok_or("--json expects a path")?);
+            }
+            ("--csv", Mode::Bench { csv, .. }) => {
+                *csv = Some(argv.next().ok_or("--csv expects a path")?);
+            }
+            ("--format", Mode::Run { format, .. }) => {
+                *format = argv.next().ok_or("--format expects a format")?.parse()?;
+            }
+            ("--render", Mode::Run { render, .. }) => {
+                *render = Some(argv.next().ok_or("--render expects a directory")?);
+            }
+            ("--image", Mode::Run { image, .. } | Mode::Animate { image, .. }) => {
+                *image = argv.next().ok_or("--image expects a format")?.parse()?;
+            }
+            ("--delay", Mode::Animate { delay, .. }) => {
+                *delay = argv
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .map(Duration::from_millis)
+                    .ok_or("--delay expects milliseconds")?;
+            }
+            ("--step", Mode::Animate { step, .. }) => *step = true,
+            ("--dump", Mode::Animate { dump, .. }) => {
+                *dump = Some(argv.next().ok_or("--dump expects a directory")?);
+            }
+            ("--no-display", Mode::Animate { display, .. }) => *display = false,
+            ("--answers", Mode::Verify { answers }) => {
+                *answers = Some(argv.next().ok_or("--answers expects a path")?);
+            }
+            ("--seed", Mode::Generate { seed, .. }) => {
+                *seed = argv
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .ok_or("--seed expects a number")?;
+            }
+            ("--seed", Mode::Run { .. }) => {
+                let seed = argv.next().and_then(|n| n.parse().ok());
+                args.seed = Some(seed.ok_or("--seed expects a number")?);
+            }
+            ("--stats", Mode::Run { .. }) => args.stats = true,
+            ("--size", Mode::Generate { size, .. }) => {
+                *size = argv
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .filter(|&n| n > 0)
+                    .ok_or("--size expects a positive number")?;
+            }
+            ("--output", Mode::Generate { output, .. }) => {
+                *output = Some(argv.next().ok_or("--output expects a path")?);
+            }
+            ("-h" | "--help", _) => return Err(String::new()),
+            (days, _) => {
+                args.days =
+                    parse_days(days).ok_or_else(|| format!("invalid day or range: {days}"))?;
+            }
+        }
+    }
+
+    if args.input.is_some() && args.days.start() != args.days.end() {
+        return Err("--input requires a single day".to_string());
+    }
+
+    if args.input.is_some() && matches!(args.mode, Mode::Verify { .. }) {
+        return Err("verify reads the inputs from the answers file".to_string());
+    }
+
+    if [args.input.is_some(), args.user.is_some(), args.all_inputs]
+        .iter()
+        .filter(|&&given| given)
+        .count()
+        > 1
+    {
+        return Err("--input, --user and --all-inputs exclude each other".to_string());
+    }
+
+    if let Mode::Run { format, .. } = args.mode {
+        if args.stats && format != output::Format::Text {
+            return Err("--stats requires the text format".to_string());
+        }
+    }
+
+    if let Mode::Generate { .. } = args.mode {
+        if args.days.start() != args.days.end() {
+            return Err("generate requires a single day".to_string());
+        }
+        if args.input.is_some() || args.user.is_some() {
+            return Err("generate does not read an input".to_string());
+        }
+    }
+
+    Ok(args)
+}
+
+fn verify(args: &Args, answers: Option<&str>) -> bool {
+    let expected = match answers {
+        Some(path) => fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| verify::parse_answers(&text))
+            .map_err(|err| format!("{path}: {err}")),
+        None => verify::registered(&aoc_2023::input_file("")),
+    };
+
+    let expected = match expected {
+        Ok(expected) => expected,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return false;
+        }
+    };
+
+    let expected: Vec<_> = expected
+        .into_iter()
+        .filter(|e| args.days.contains(&e.day))
+        .filter(|e| match &args.user {
+            Some(user) => e.input.starts_with(&format!("{user}/")),
+            None => true,
+        })
+        .filter(|e| match args.part {
+            Part::One => e.part == 1,
+            Part::Two => e.part == 2,
+            Part::Both => true,
+        })
+        .collect();
+
+    let outcomes = verify::verify(&expected, args.timeout);
+    let passed = outcomes.iter().filter(|o| o.status == Status::Pass).count();
+
+    for outcome in &outcomes {
+        println!("{outcome}");
+    }
+    println!("{passed}/{} passed", outcomes.len());
+
+    passed == outcomes.len()
+}
+
+/// Inputs to run `day` on, as the name to print above the results and the
+/// path for [`aoc_2023::read_input`].
+fn inputs(args: &Args, registered: &[verify::Expected], day: u32) -> Vec<(String, Option<String>)> {
+    let path = |name: &str| Some(aoc_2023::input_file(name).display().to_string());
+
+    if args.all_inputs {
+        verify::inputs(registered, day)
+            .into_iter()
+            .map(|name| (name.to_string(), path(name)))
+            .collect()
+    } else if let Some(user) = &args.user {
+        let name = format!("{user}/input{day:02}.txt");
+        vec![(name.clone(), path(&name))]
+    } else {
+        let name = args.input.clone().unwrap_or(format!("input{day:02}.txt"));
+        vec![(name, args.input.clone())]
+    }
+}
+
+fn render(solution: &Day, input: &str, dir: &str, format: Format) -> Result<(), String> {
+    let Some(canvas) = solution.render(input).map_err(|e| e.to_string())? else {
+        return Ok(());
+    };
+
+    let path = Path::new(dir).join(format!("day{:02}.{}", solution.day, format.extension()));
+    fs::create_dir_all(dir)
+        .and_then(|_| fs::write(&path, canvas.encode(format, SCALE)))
+        .map_err(|e| format!("cannot write {}: {e}", path.display()))
+}
+
+fn animate(solution: &Day, input: &str, mode: &Mode) -> Result<(), String> {
+    let Mode::Animate {
+        delay,
+        step,
+        dump,
+        image,
+        display,
+    } = mode
+    else {
+        unreachable!()
+    };
+
+    let mut player = Player::new(*delay);
+    player.paused = *step;
+    player.display = *display;
+    if let Some(dir) = dump {
+        let dir = Path::new(dir).join(format!("day{:02}", solution.day));
+        player = player.dump(dir, *image, SCALE);
+    }
+
+    solution
+        .animate(input, &mut |canvas| player.show(canvas))
+        .map_err(|e| e.to_string())?;
+
+    if player.frames() == 0 {
+        println!("day {:02} has no animation", solution.day);
+    }
+
+    Ok(())
+}
+
+fn stats(solution: &Day, input: &str, seed: Option<u64>) -> Result<(), String> {
+    for (name, value) in solution.stats(input, seed).map_err(|e| e.to_string())? {
+        println!("{name} = {value}");
+    }
+    Ok(())
+}
+
+fn validate(solution: &Day, input: &str) -> Result<(), String> {
+    let assumptions = solution.validate(input).map_err(|e| e.to_string())?;
+
+    if assumptions.is_empty() {
+        println!("  no assumptions to check");
+    }
+    for assumption in &assumptions {
+        println!("  {assumption}");
+    }
+
+    match assumptions.iter().filter(|a| !a.holds()).count() {
+        0 => Ok(()),
+        1 => Err("1 assumption does not hold".to_string()),
+        n => Err(format!("{n} assumptions do not hold")),
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(msg) => {
+            if !msg.is_empty() {
+                eprintln!("error: {msg}\n");
+            }
+            eprintln!("{USAGE}");
+            process::exit(2);
+        }
+    };
+
+    if let Mode::Verify { answers } = &args.mode {
+        if !verify(&args, answers.as_deref()) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    if let Mode::Generate { seed, size, output } = &args.mode {
+        let day = *args.days.start();
+        let Some(solution) = aoc_2023::day(day) else {
+            eprintln!("error: day {day} is not solved");
+            process::exit(1);
+        };
+
+        let input = solution.generate(*seed, *size);
+        match output {
+            Some(path) => {
+                if let Err(err) = fs::write(path, input) {
+                    eprintln!("error: cannot write {path}: {err}");
+                    process::exit(1);
+                }
+            }
+            None => print!("{input}"),
+        }
+        return;
+    }
+
+    let registered = if args.all_inputs {
+        verify::registered(&aoc_2023::input_file("")).unwrap_or_else(|err| {
+            eprintln!("error: {err}");
+            process::exit(1);
+        })
+    } else {
+        vec![]
+    };
+
+    let multiple = args.days.start() != args.days.end() || args.all_inputs;
+    let mut failed = false;
+    let mut reports = vec![];
+    let mut records = vec![];
+    let structured = matches!(
+        args.mode,
+        Mode::Run {
+            format: output::Format::Json | output::Format::Tsv,
+            ..
+        }
+    );
+
+    for (day, (name, path)) in args.days.clone().flat_map(|day| {
+        inputs(&args, &registered, day)
+            .into_iter()
+            .map(move |i| (day, i))
+    }) {
+        let input = match aoc_2023::read_input(day, path.as_deref()) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: {err}");
+                failed = true;
+                if structured {
+                    let error = output::Error {
+                        kind: ErrorKind::Input,
+                        message: err.to_string(),
+                    };
+                    records.extend(output::failed(day, &name, args.part, error));
+                }
+                continue;
+            }
+        };
+
+        let Some(solution) = aoc_2023::day(day) else {
+            eprintln!("error: day {day} is not solved");
+            failed = true;
+            continue;
+        };
+
+        if multiple && matches!(args.mode, Mode::Run { .. }) && !structured
+            || matches!(args.mode, Mode::Validate)
+        {
+            if args.all_inputs {
+                println!("day {day:02} {name}");
+            } else {
+                println!("day {day:02}");
+            }
+        }
+
+        let result = cancel::isolate(args.timeout, || match args.mode {
+            Mode::Run {
+                ref render, image, ..
+            } => {
+                let solved = if structured {
+                    let solved = output::solve(solution, &input, &name, args.part, args.seed);
+                    failed |= solved.iter().any(|r| r.result.is_err());
+                    records.extend(solved);
+                    Ok(())
+                } else {
+                    solution
+                        .time_seeded(&input, args.part, args.seed)
+                        .map_err(|e| e.to_string())
+                        .map(|(answers, _)| print!("{answers}"))
+                };
+
+                let solved = if args.stats {
+                    solved.and_then(|_| stats(solution, &input, args.seed))
+                } else {
+                    solved
+                };
+
+                solved.and_then(|_| match render {
+                    Some(dir) => self::render(solution, &input, dir, image),
+                    None => Ok(()),
+                })
+            }
+            Mode::Bench { runs, .. } => bench::bench(solution, &input, args.part, runs)
+                .map_err(|e| e.to_string())
+                .map(|report| reports.push(report)),
+            Mode::Animate { .. } => animate(solution, &input, &args.mode),
+            Mode::Validate => validate(solution, &input),
+            Mode::Verify { .. } | Mode::Generate { .. } => unreachable!(),
+        })
+        .unwrap_or_else(|abort| Err(abort.to_string()));
+
+        if let Err(err) = result {
+            eprintln!("error: day {day:02}: {err}");
+            failed = true;
+        }
+    }
+
+    match args.mode {
+        Mode::Run {
+            format: output::Format::Json,
+            ..
+        } => print!("{}", output::to_json(&records)),
+        Mode::Run {
+            format: output::Format::Tsv,
+            ..
+        } => print!("{}", output::to_tsv(&records)),
+        _ => (),
+    }
+
+    if let Mode::Bench { json, csv, .. } = &args.mode {
+        print!("{}", bench::to_table(&reports));
+
+        for (path, report) in [
+            (json, bench::to_json(&reports)),
+            (csv, bench::to_csv(&reports)),
+        ] {
+            if let Some(path) = path {
+                if let Err(err) = fs::write(path, report) {
+                    eprintln!("error: cannot write {path}: {err}");
+                    failed = true;
+                }
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
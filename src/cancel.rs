@@ -0,0 +1,120 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+thread_local! {
+    /// Deadline of the current [`isolate`] call and its timeout.
+    static DEADLINE: Cell<Option<(Instant, Duration)>> = const { Cell::new(None) };
+    static CHECKS: Cell<u32> = const { Cell::new(0) };
+}
+
+/// Unwinding payload of [`check`] past the deadline.
+struct TimedOut(Duration);
+
+/// Why an [`isolate`]d run did not finish.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Abort {
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl fmt::Display for Abort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Abort::Panicked(msg) => write!(f, "panicked: {msg}"),
+            Abort::TimedOut(timeout) => write!(f, "timed out after {timeout:?}"),
+        }
+    }
+}
+
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(msg), _) => msg,
+        (_, Some(msg)) => msg,
+        _ => "unknown panic",
+    }
+}
+
+/// Resets the deadline when [`isolate`] returns or unwinds.
+struct Restore(Option<(Instant, Duration)>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        DEADLINE.with(|d| d.set(self.0));
+    }
+}
+
+/// Runs `f`, catching its panics. Long searches call [`check`] so that they
+/// are stopped once `timeout` has passed; solutions that never call it run to
+/// the end. Nested calls keep the earlier deadline of an outer call.
+pub fn isolate<T>(timeout: Option<Duration>, f: impl FnOnce() -> T) -> Result<T, Abort> {
+    let outer = DEADLINE.with(Cell::get);
+    let _restore = Restore(outer);
+
+    if let Some(timeout) = timeout {
+        let deadline = Instant::now() + timeout;
+        if outer.is_none_or(|(outer, _)| deadline < outer) {
+            DEADLINE.with(|d| d.set(Some((deadline, timeout))));
+        }
+    }
+
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| match payload.downcast_ref() {
+        Some(&TimedOut(timeout)) => Abort::TimedOut(timeout),
+        None => Abort::Panicked(panic_message(payload.as_ref()).to_string()),
+    })
+}
+
+/// Cancellation point: unwinds out of the enclosing [`isolate`] call once its
+/// deadline has passed. Cheap enough for the inner loop of a search, the
+/// clock is only read every few hundred calls.
+pub fn check() {
+    let checks = CHECKS.with(|c| {
+        c.set(c.get().wrapping_add(1));
+        c.get()
+    });
+    if !checks.is_multiple_of(256) {
+        return;
+    }
+
+    if let Some((deadline, timeout)) = DEADLINE.with(Cell::get) {
+        if Instant::now() >= deadline {
+            // without the panic hook, a timeout is not an error message
+            panic::resume_unwind(Box::new(TimedOut(timeout)));
+        }
+    }
+}
+
+#[test]
+fn test_cancel() {
+    let ms = Duration::from_millis;
+
+    assert_eq!(isolate(Some(ms(10)), || 42), Ok(42));
+    assert_eq!(
+        isolate(None, || panic!("boom")),
+        Err::<(), _>(Abort::Panicked("boom".to_string()))
+    );
+
+    let start = Instant::now();
+    let result = isolate(Some(ms(10)), || loop {
+        check();
+    });
+    assert_eq!(result, Err::<(), _>(Abort::TimedOut(ms(10))));
+    assert!(start.elapsed() < ms(1000));
+    assert_eq!(Abort::TimedOut(ms(10)).to_string(), "timed out after 10ms");
+
+    // the inner call keeps the earlier deadline of the outer one
+    let result = isolate(Some(ms(10)), || {
+        isolate(Some(ms(10_000)), || loop {
+            check();
+        })
+    });
+    assert_eq!(result, Ok(Err::<(), _>(Abort::TimedOut(ms(10)))));
+
+    // the deadline is gone after the call
+    assert!(DEADLINE.with(Cell::get).is_none());
+}
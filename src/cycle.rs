@@ -0,0 +1,154 @@
+use std::hash::Hash;
+
+use ahash::AHashMap;
+
+/// Shape of the sequence `start, step(start), step(step(start)), ...` of a
+/// deterministic simulation: after `prefix` steps the states repeat every
+/// `period` steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The step in `0..prefix + period` that reaches the same state as step
+    /// `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+/// Applies `step` `n` times.
+pub fn iterate<S, F>(start: S, mut step: F, n: usize) -> S
+where
+    F: FnMut(&S) -> S,
+{
+    (0..n).fold(start, |state, _| step(&state))
+}
+
+/// Finds the cycle by remembering every state. Also returns the states
+/// before the first repetition, so the state after `n` steps is
+/// `states[cycle.reduce(n)]`.
+pub fn find<S, F>(start: S, mut step: F) -> (Cycle, Vec<S>)
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = AHashMap::new();
+    let mut states = vec![];
+    let mut state = start;
+
+    loop {
+        if let Some(&prefix) = seen.get(&state) {
+            let period = states.len() - prefix;
+            return (Cycle { prefix, period }, states);
+        }
+
+        let next = step(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = next;
+    }
+}
+
+// https://en.wikipedia.org/wiki/Cycle_detection#Brent's_algorithm
+/// Finds the cycle with constant memory. Use [`iterate`] with
+/// [`Cycle::reduce`] to get the state after a large number of steps.
+pub fn brent<S, F>(start: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    let mut tortoise = start.clone();
+    let mut hare = iterate(start, &mut step, period);
+    let mut prefix = 0;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+// https://en.wikipedia.org/wiki/Cycle_detection#Floyd's_tortoise_and_hare
+/// Finds the cycle with constant memory, usually needing more steps than
+/// [`brent`].
+pub fn floyd<S, F>(start: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    let mut prefix = 0;
+    tortoise = start;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+#[test]
+fn test_cycle() {
+    // 0, 1, 2, 3, 4, 5, 3, 4, 5, ...
+    let step = |&n: &u32| if n == 5 { 3 } else { n + 1 };
+    let expected = Cycle {
+        prefix: 3,
+        period: 3,
+    };
+
+    let (cycle, states) = find(0, step);
+    assert_eq!(cycle, expected);
+    assert_eq!(states, [0, 1, 2, 3, 4, 5]);
+    assert_eq!(brent(0, step), expected);
+    assert_eq!(floyd(0, step), expected);
+
+    assert_eq!(cycle.reduce(2), 2);
+    assert_eq!(states[cycle.reduce(1_000_000_000)], 4);
+    assert_eq!(iterate(0, step, cycle.reduce(1_000_000_000)), 4);
+
+    let cycle = brent(7, |&n: &u32| (n * n + 1) % 255);
+    let (expected, _) = find(7, |&n: &u32| (n * n + 1) % 255);
+    assert_eq!(cycle, expected);
+}
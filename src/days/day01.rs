@@ -0,0 +1,141 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::parse::{Cursor, ParseError};
+use crate::Solution;
+
+fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    Cursor::new(input).lines(|c| Ok(c.word()?.to_string()))
+}
+
+fn part1(puzzle: &[String]) -> u32 {
+    let mut sum = 0;
+    for line in puzzle.iter() {
+        let digits: Vec<_> = line.chars().filter(|c| c.is_ascii_digit()).collect();
+
+        if let (Some(first), Some(last)) = (
+            digits.first().and_then(|c| c.to_digit(10)),
+            digits.last().and_then(|c| c.to_digit(10)),
+        ) {
+            sum += first * 10 + last;
+        }
+    }
+
+    sum
+}
+
+fn replace_numbers(line: &str) -> String {
+    let numbers = [
+        ("one", "1"),
+        ("two", "2"),
+        ("three", "3"),
+        ("four", "4"),
+        ("five", "5"),
+        ("six", "6"),
+        ("seven", "7"),
+        ("eight", "8"),
+        ("nine", "9"),
+    ];
+
+    let mut replaced = String::new();
+
+    for i in 0..line.len() {
+        let rest = &line[i..];
+        let mut found = false;
+
+        for (name, number) in numbers {
+            if rest.starts_with(name) {
+                replaced.push_str(number);
+                found = true;
+                break;
+            }
+        }
+
+        if !found {
+            replaced.push(line.chars().nth(i).unwrap());
+        }
+    }
+
+    replaced
+}
+
+fn part2(puzzle: &[String]) -> u32 {
+    let translated: Vec<_> = puzzle.iter().map(|s| replace_numbers(s)).collect();
+
+    part1(&translated)
+}
+
+/// `size` lines of letters, digits and spelled out digits.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    const WORDS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    let mut input = String::new();
+
+    for _ in 0..size {
+        for _ in 0..rng.gen_range(1..8) {
+            match rng.gen_range(0..3) {
+                0 => input.push(char::from(rng.gen_range(b'1'..=b'9'))),
+                1 => input.push_str(WORDS.choose(rng).unwrap()),
+                _ => input.push(char::from(rng.gen_range(b'a'..=b'z'))),
+            }
+        }
+
+        // part 1 needs a digit in every line
+        input.push(char::from(rng.gen_range(b'1'..=b'9')));
+        input.push('\n');
+    }
+
+    input
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+
+    type Input<'a> = Vec<String>;
+    type Config = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<String>, _: &()) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<String>, _: &()) -> u32 {
+        part2(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day01() {
+    let input = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 142);
+
+    let input = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+    let input = parse(input).unwrap();
+    assert_eq!(part2(&input), 281);
+}
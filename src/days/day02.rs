@@ -0,0 +1,134 @@
+use std::fmt::Write;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::parse::{Cursor, ParseError};
+use crate::Solution;
+
+/// A game: its id and the cubes shown in each set, as count and colour.
+#[derive(Debug)]
+pub struct Game {
+    id: i32,
+    sets: Vec<Vec<(i32, String)>>,
+}
+
+fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    Cursor::new(input).lines(|c| {
+        c.tag("Game")?;
+        let id = c.uint()?;
+        c.tag(":")?;
+
+        let sets = c.separated(";", |c| {
+            c.separated(",", |c| Ok((c.uint()?, c.ident()?.to_string())))
+        })?;
+
+        Ok(Game { id, sets })
+    })
+}
+
+fn max_colors(game: &Game) -> (i32, i32, i32) {
+    let mut max = (0, 0, 0);
+    for set in &game.sets {
+        for (num, color) in set {
+            match color.as_ref() {
+                "red" => max.0 = max.0.max(*num),
+                "green" => max.1 = max.1.max(*num),
+                "blue" => max.2 = max.2.max(*num),
+                _ => (),
+            }
+        }
+    }
+
+    max
+}
+
+fn part1(puzzle: &[Game]) -> i32 {
+    let mut sum = 0;
+    for game in puzzle {
+        let (red, green, blue) = max_colors(game);
+        if red <= 12 && green <= 13 && blue <= 14 {
+            sum += game.id;
+        }
+    }
+
+    sum
+}
+
+fn part2(puzzle: &[Game]) -> i32 {
+    let mut sum = 0;
+
+    for game in puzzle.iter() {
+        let (red, green, blue) = max_colors(game);
+        sum += red * green * blue;
+    }
+
+    sum
+}
+
+/// `size` games of up to six draws.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+
+    for id in 1..=size {
+        let draws: Vec<_> = (0..rng.gen_range(1..=6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                colors.shuffle(rng);
+                let count = rng.gen_range(1..=3);
+
+                colors[..count]
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.gen_range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+
+        writeln!(input, "Game {id}: {}", draws.join("; ")).unwrap();
+    }
+
+    input
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+
+    type Input<'a> = Vec<Game>;
+    type Config = ();
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Game>, _: &()) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Game>, _: &()) -> i32 {
+        part2(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day02() {
+    let input = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 8);
+    assert_eq!(part2(&input), 2286);
+}
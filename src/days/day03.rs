@@ -0,0 +1,237 @@
+use ahash::AHashMap;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::grid::{Grid, Point};
+use crate::parse::{Cursor, ParseError};
+use crate::render::{Canvas, Color};
+use crate::Solution;
+
+/// Engine schematic: the symbols by position and every part number with
+/// the tiles it covers.
+pub struct Schematic {
+    width: usize,
+    height: usize,
+    symbols: AHashMap<(i64, i64), char>,
+    numbers: Vec<(Vec<(i64, i64)>, i64)>,
+}
+
+fn parse(input: &str) -> Result<Schematic, ParseError> {
+    let tiles = Cursor::new(input).grid("digit, `.` or symbol", |t| {
+        (!t.is_whitespace()).then_some(t)
+    })?;
+    let mut numbers = vec![];
+    let mut symbols = AHashMap::new();
+
+    for (y, row) in tiles.rows().enumerate() {
+        let mut num = 0;
+        let mut coords = vec![]; // faster than a hashset in this case
+
+        for (x, &ch) in row.iter().enumerate() {
+            if let Some(digit) = ch.to_digit(10) {
+                num = num * 10 + digit as i64;
+                coords.push((x as i64, y as i64));
+            } else {
+                if ch != '.' {
+                    symbols.insert((x as i64, y as i64), ch);
+                }
+                if !coords.is_empty() {
+                    numbers.push((std::mem::take(&mut coords), num));
+                    num = 0;
+                }
+            }
+        }
+
+        if !coords.is_empty() {
+            numbers.push((coords, num));
+        }
+    }
+
+    Ok(Schematic {
+        width: tiles.width(),
+        height: tiles.height(),
+        symbols,
+        numbers,
+    })
+}
+
+fn adjacent((x, y): (i64, i64)) -> [(i64, i64); 8] {
+    let dirs = [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, -1),
+        (0, 1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+    ];
+    let mut adj = [(0, 0); 8];
+
+    for (i, (dir_x, dir_y)) in dirs.iter().enumerate() {
+        adj[i] = (dir_x + x, dir_y + y);
+    }
+
+    adj
+}
+
+fn part1(puzzle: &Schematic) -> i64 {
+    let mut sum = 0;
+
+    for symbol_pos in puzzle.symbols.keys() {
+        let adj = adjacent(*symbol_pos);
+        for (coords, number) in &puzzle.numbers {
+            if adj.iter().any(|c| coords.contains(c)) {
+                sum += number;
+            }
+        }
+    }
+
+    sum
+}
+
+fn part2(puzzle: &Schematic) -> i64 {
+    let mut sum = 0;
+
+    for (symbol_pos, _) in puzzle.symbols.iter().filter(|(_, &sy)| sy == '*') {
+        let adj = adjacent(*symbol_pos);
+        let mut adj_nums = vec![];
+        for (coords, number) in &puzzle.numbers {
+            if adj.iter().any(|c| coords.contains(c)) {
+                adj_nums.push(number);
+            }
+        }
+
+        if let [first, second] = adj_nums[..] {
+            sum += first * second;
+        }
+    }
+
+    sum
+}
+
+/// Part numbers in green and the other numbers in red, gears in orange and
+/// the other symbols in yellow.
+fn render(puzzle: &Schematic) -> Canvas {
+    let mut canvas = Canvas::new(&Grid::filled(puzzle.width, puzzle.height, ()), |_| {
+        Color::BLACK
+    });
+    let point = |(x, y)| Point::new(x, y);
+
+    for (coords, _) in &puzzle.numbers {
+        let part = coords
+            .iter()
+            .any(|&c| adjacent(c).iter().any(|a| puzzle.symbols.contains_key(a)));
+        for &c in coords {
+            canvas.fill(point(c), if part { Color::GREEN } else { Color::RED });
+        }
+    }
+    for (&pos, &symbol) in &puzzle.symbols {
+        let adj = adjacent(pos);
+        let numbers = puzzle
+            .numbers
+            .iter()
+            .filter(|(coords, _)| adj.iter().any(|c| coords.contains(c)))
+            .count();
+        let gear = symbol == '*' && numbers == 2;
+        canvas.fill(point(pos), if gear { Color::ORANGE } else { Color::YELLOW });
+    }
+
+    canvas
+}
+
+/// Schematic of `size` x `size` tiles.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    const SYMBOLS: &[u8] = b"*#+$/@=%&-";
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let mut row = String::new();
+
+        while row.len() < size {
+            match rng.gen_range(0..10) {
+                0 | 1 => {
+                    let number = rng.gen_range(1..1000).to_string();
+                    if row.len() + number.len() <= size {
+                        row.push_str(&number);
+                    }
+                    // numbers in a row are separated by at least one tile
+                    row.push('.');
+                }
+                2 => row.push(char::from(*SYMBOLS.choose(rng).unwrap())),
+                _ => row.push('.'),
+            }
+        }
+
+        row.truncate(size);
+        input.push_str(&row);
+        input.push('\n');
+    }
+
+    input
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+
+    type Input<'a> = Schematic;
+    type Config = ();
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Schematic, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Schematic, _: &()) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Schematic, _: &()) -> i64 {
+        part2(input)
+    }
+
+    fn render(input: &Schematic, _: &()) -> Option<Canvas> {
+        Some(render(input))
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day03() {
+    let input = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 4361);
+    assert_eq!(part2(&input), 467835);
+}
+
+#[test]
+fn test_day03_render() {
+    let input = parse(include_str!("../../examples/day03/example.txt")).unwrap();
+
+    let image = render(&input).to_image(1);
+    assert_eq!((image.width, image.height), (10, 10));
+    // 467 is a part number, 114 is not and the `*` below them is a gear
+    let pixel = |x: usize, y: usize| image.pixels[y * 10 + x];
+    assert_eq!(
+        [pixel(0, 0), pixel(5, 0), pixel(3, 1), pixel(6, 3)],
+        [Color::GREEN, Color::RED, Color::ORANGE, Color::YELLOW]
+    );
+}
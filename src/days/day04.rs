@@ -0,0 +1,136 @@
+use std::fmt::Write;
+
+use ahash::AHashSet;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::parse::{Cursor, ParseError};
+use crate::Solution;
+
+/// Winning numbers and the numbers you have of a scratchcard.
+pub type Card = (AHashSet<usize>, AHashSet<usize>);
+
+fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    Cursor::new(input).lines(|c| {
+        c.tag("Card")?;
+        c.uint::<usize>()?;
+        c.tag(":")?;
+        let winning = c.many(Cursor::uint)?;
+        c.tag("|")?;
+        let mine = c.many(Cursor::uint)?;
+
+        Ok((winning.into_iter().collect(), mine.into_iter().collect()))
+    })
+}
+
+fn part1(cards: &[Card]) -> usize {
+    let mut sum = 0;
+
+    for (winning, mine) in cards {
+        let count = winning.intersection(mine).count();
+        if count > 0 {
+            sum += 1 << (count - 1)
+        }
+    }
+
+    sum
+}
+
+fn part2(cards: &[Card]) -> usize {
+    let mut unused_cards = Vec::from_iter(0..cards.len());
+    let mut used_cards = 0;
+
+    let winnings = cards
+        .iter()
+        .map(|(winning, mine)| winning.intersection(mine).count())
+        .collect::<Vec<_>>();
+
+    while let Some(card) = unused_cards.pop() {
+        used_cards += 1;
+
+        for c in card + 1..card + 1 + winnings[card] {
+            unused_cards.push(c);
+        }
+    }
+
+    used_cards
+}
+
+/// `size` cards with 10 winning numbers and 25 numbers you have.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+
+    for id in 1..=size {
+        // most cards lose so that the copies of part 2 stay countable, and
+        // no card wins copies of cards past the end of the table
+        let limit = 10.min(size - id);
+        let matches = if limit == 0 || rng.gen_bool(0.7) {
+            0
+        } else {
+            rng.gen_range(1..=limit)
+        };
+
+        let mut numbers = rand::seq::index::sample(rng, 99, 35 - matches).into_vec();
+        numbers.iter_mut().for_each(|n| *n += 1);
+        let (winning, rest) = numbers.split_at(10);
+        let mut have = [&winning[..matches], rest].concat();
+        have.shuffle(rng);
+
+        let column = |numbers: &[usize]| {
+            let numbers: Vec<_> = numbers.iter().map(|n| format!("{n:2}")).collect();
+            numbers.join(" ")
+        };
+        writeln!(
+            input,
+            "Card {id:3}: {} | {}",
+            column(winning),
+            column(&have)
+        )
+        .unwrap();
+    }
+
+    input
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+
+    type Input<'a> = Vec<Card>;
+    type Config = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Card>, _: &()) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Card>, _: &()) -> usize {
+        part2(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day04() {
+    let input = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 13);
+    assert_eq!(part2(&input), 30);
+}
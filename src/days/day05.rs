@@ -0,0 +1,328 @@
+use std::fmt::Write;
+use std::ops::Range;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::parse::{Cursor, ParseError};
+use crate::Solution;
+
+/// The seeds and the seven maps from seed to location, each a list of
+/// `(destination, source, length)` ranges.
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<i64>,
+    maps: Vec<Vec<(i64, i64, i64)>>,
+}
+
+fn parse(input: &str) -> Result<Almanac, ParseError> {
+    let mut c = Cursor::new(input);
+
+    let mut first = c.block();
+    first.tag("seeds:")?;
+    let seeds = first.many(Cursor::int)?;
+    // part 2 reads the seeds as pairs of start and length
+    match seeds.len() {
+        0 => return Err(first.error("seed")),
+        n if n % 2 == 1 => return Err(first.error("length of the last seed range")),
+        _ => first.end()?,
+    }
+
+    let maps = c.blocks(|c| {
+        c.word()?;
+        c.tag("map:")?;
+        c.tag("\n")?;
+        c.lines(|c| Ok((c.int()?, c.int()?, c.int()?)))
+    })?;
+
+    Ok(Almanac { seeds, maps })
+}
+
+impl Almanac {
+    /// Location of `seed` after all maps.
+    pub fn location(&self, seed: i64) -> i64 {
+        let mut state = seed;
+        for map in &self.maps {
+            for &(dest, source, length) in map {
+                if (source..source + length).contains(&state) {
+                    state = state - source + dest;
+                    break;
+                }
+            }
+        }
+
+        state
+    }
+
+    /// Locations of all numbers in `ranges` after all maps, as ranges. Maps
+    /// split a range where their source ranges start and end.
+    pub fn map_ranges(&self, mut ranges: Vec<Range<i64>>) -> Vec<Range<i64>> {
+        for map in &self.maps {
+            let mut new_ranges = vec![];
+
+            for &(dest, source, length) in map {
+                let start = source;
+                let end = source + length;
+                let mut rest = vec![];
+
+                while let Some(range) = ranges.pop() {
+                    let overlap = range.start.max(start)..range.end.min(end);
+                    if overlap.is_empty() {
+                        rest.push(range);
+                        continue;
+                    }
+
+                    new_ranges.push(overlap.start - source + dest..overlap.end - source + dest);
+                    // the parts left and right of the map range stay unmapped
+                    if range.start < overlap.start {
+                        rest.push(range.start..overlap.start);
+                    }
+                    if overlap.end < range.end {
+                        rest.push(overlap.end..range.end);
+                    }
+                }
+
+                ranges = rest;
+            }
+            ranges.extend(new_ranges);
+        }
+
+        ranges
+    }
+}
+
+fn part1(almanac: &Almanac) -> i64 {
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| almanac.location(seed))
+        .min()
+        .unwrap_or(i64::MAX)
+}
+
+fn part2(almanac: &Almanac) -> i64 {
+    let ranges = almanac.seeds.chunks(2).map(|c| c[0]..c[0] + c[1]).collect();
+
+    almanac
+        .map_ranges(ranges)
+        .iter()
+        .map(|r| r.start)
+        .min()
+        .unwrap_or(i64::MAX)
+}
+
+/// Almanac with ten seed ranges and `size` ranges per map.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    const MAX: i64 = 4_000_000_000;
+    const NAMES: [&str; 8] = [
+        "seed",
+        "soil",
+        "fertilizer",
+        "water",
+        "light",
+        "temperature",
+        "humidity",
+        "location",
+    ];
+
+    let seeds: Vec<_> = (0..10)
+        .map(|_| {
+            let start = rng.gen_range(0..MAX / 2);
+            format!("{start} {}", rng.gen_range(1..MAX / 20))
+        })
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for names in NAMES.windows(2) {
+        write!(input, "\n{}-to-{} map:\n", names[0], names[1]).unwrap();
+
+        // disjoint source ranges between sorted cut points
+        let mut cuts: Vec<_> = (0..2 * size.max(1))
+            .map(|_| rng.gen_range(0..MAX))
+            .collect();
+        cuts.sort_unstable();
+        cuts.dedup();
+
+        for range in cuts.chunks_exact(2) {
+            let (source, len) = (range[0], range[1] - range[0]);
+            if len > 0 {
+                let dest = rng.gen_range(0..MAX - len);
+                writeln!(input, "{dest} {source} {len}").unwrap();
+            }
+        }
+    }
+
+    input
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+
+    type Input<'a> = Almanac;
+    type Config = ();
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Almanac, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Almanac, _: &()) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Almanac, _: &()) -> i64 {
+        part2(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+#[allow(clippy::single_range_in_vec_init)]
+fn test_day05() {
+    let input = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 35);
+    assert_eq!(part2(&input), 46);
+    assert_eq!(input.location(79), 82);
+    let locations = input.map_ranges(vec![79..93]);
+    assert_eq!(locations.iter().map(|r| r.start).min(), Some(46));
+}
+
+#[test]
+#[allow(clippy::single_range_in_vec_init)]
+fn test_day05_whole_range() {
+    // a seed range covering a map range exactly leaves nothing unmapped, not
+    // empty ranges whose start would be the lowest location
+    let input = parse("seeds: 10 5\n\na-to-b map:\n100 10 5\n").unwrap();
+
+    assert_eq!(part2(&input), 100);
+    assert_eq!(input.map_ranges(vec![10..15]), [100..105]);
+}
+
+#[test]
+fn test_day05_reference() {
+    use crate::property;
+
+    // follows every single seed through the maps
+    fn lowest(almanac: &Almanac) -> i64 {
+        let seeds = almanac.seeds.chunks(2).flat_map(|c| c[0]..c[0] + c[1]);
+
+        part1(&Almanac {
+            seeds: seeds.collect(),
+            maps: almanac.maps.clone(),
+        })
+    }
+
+    // the real input has no empty ranges, and the source ranges of a map
+    // are disjoint
+    fn disjoint(map: &[(i64, i64, i64)]) -> bool {
+        let mut ranges: Vec<_> = map.iter().map(|&(_, source, len)| (source, len)).collect();
+        ranges.sort_unstable();
+
+        ranges.iter().all(|&(_, len)| len > 0)
+            && ranges.windows(2).all(|w| w[0].0 + w[0].1 <= w[1].0)
+    }
+
+    let almanac = |rng: &mut StdRng| {
+        let seeds: Vec<_> = (0..rng.gen_range(1..=3))
+            .map(|_| format!("{} {}", rng.gen_range(0..100), rng.gen_range(1..20)))
+            .collect();
+        let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+        for map in 0..rng.gen_range(1..=3) {
+            write!(input, "\n{map}-to-{} map:\n", map + 1).unwrap();
+
+            let mut cuts: Vec<_> = (0..2 * rng.gen_range(1..=3))
+                .map(|_| rng.gen_range(0..120))
+                .collect();
+            cuts.sort_unstable();
+            for range in cuts.chunks_exact(2) {
+                let dest = rng.gen_range(0..120);
+                writeln!(input, "{dest} {} {}", range[0], range[1] - range[0] + 1).unwrap();
+            }
+        }
+
+        input
+    };
+
+    let result = property::check(
+        500,
+        almanac,
+        |text| {
+            [
+                property::remove_lines(text),
+                property::smaller_numbers(text),
+            ]
+            .concat()
+        },
+        |text| {
+            let Ok(almanac) = parse(text) else {
+                return Ok(());
+            };
+            let seeds = almanac.seeds.chunks(2);
+            if seeds.len() == 0
+                || seeds.into_iter().any(|c| c.len() < 2 || c[1] <= 0)
+                || !almanac.maps.iter().all(|map| disjoint(map))
+            {
+                return Ok(());
+            }
+
+            property::compare("part 2", part2(&almanac), lowest(&almanac))
+        },
+    );
+
+    if let Err(failure) = result {
+        panic!("{failure}");
+    }
+}
+
+#[test]
+fn test_day05_errors() {
+    let err = parse("seeds: 79 14 55\n\na-to-b map:\n1 2 3\n").unwrap_err();
+    assert_eq!((err.line, err.column), (1, 16));
+    let err = parse("seeds:\n\na-to-b map:\n1 2 3\n").unwrap_err();
+    assert_eq!(
+        (err.line, err.column, err.expected.as_str()),
+        (1, 7, "seed")
+    );
+}
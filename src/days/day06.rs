@@ -0,0 +1,189 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::parse::{Cursor, ParseError};
+use crate::Solution;
+
+/// Time and record distance of every race, and of the single race that
+/// results from ignoring the spaces.
+pub struct Races {
+    races: Vec<(i64, i64)>,
+    race: (i64, i64),
+}
+
+fn parse(input: &str) -> Result<Races, ParseError> {
+    Ok(Races {
+        races: parse1(input)?,
+        race: parse2(input)?,
+    })
+}
+
+fn parse1(input: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    let mut c = Cursor::new(input);
+
+    c.tag("Time:")?;
+    let times = c.many(Cursor::uint)?;
+    c.tag("\n")?;
+    c.tag("Distance:")?;
+    let distances = c.many(Cursor::uint)?;
+    c.end()?;
+
+    Ok(times.into_iter().zip(distances).collect())
+}
+
+fn parse2(input: &str) -> Result<(i64, i64), ParseError> {
+    let mut c = Cursor::new(input);
+
+    // the numbers are one number with bad kerning
+    let kerned = |c: &mut Cursor, label| {
+        c.tag(label)?;
+        let digits = c.many(|c| c.token(|d| d.is_ascii_digit(), "digits"))?;
+        let error = c.error_at(digits.first().copied().unwrap_or(c.rest()), "number");
+
+        digits.concat().parse().map_err(|_| error)
+    };
+
+    let time = kerned(&mut c, "Time:")?;
+    c.tag("\n")?;
+    let distance = kerned(&mut c, "Distance:")?;
+    c.end()?;
+
+    Ok((time, distance))
+}
+
+fn possible_records(time: f64, record: f64) -> i64 {
+    // applied quadratic formula
+    let zero_point = |sig| {
+        // 0.01 because we want to be better than the record
+        (-time + sig * f64::sqrt(time * time - 4.0 * (record + 0.01))) / -2.0
+    };
+
+    (zero_point(-1.0).ceil() - zero_point(1.0).ceil()) as i64
+}
+
+fn part1(puzzle: &[(i64, i64)]) -> i64 {
+    puzzle
+        .iter()
+        .map(|&(time, record)| possible_records(time as f64, record as f64))
+        .product()
+}
+
+fn part2((time, record): (i64, i64)) -> i64 {
+    possible_records(time as f64, record as f64)
+}
+
+/// `size` races, at most four so that the kerned numbers of part 2 fit into
+/// an `i64`.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut times = vec![];
+    let mut records = vec![];
+
+    for _ in 0..size.clamp(1, 4) {
+        // two digit times keep the kerned race of part 2 winnable
+        let time = rng.gen_range(10..100);
+        let best = (time / 2) * (time - time / 2);
+        times.push(time);
+        records.push(rng.gen_range(0..best));
+    }
+
+    let column = |numbers: &[i64]| {
+        numbers
+            .iter()
+            .map(|n| format!("{n:>5}"))
+            .collect::<String>()
+    };
+
+    format!(
+        "Time:    {}\nDistance:{}\n",
+        column(&times),
+        column(&records)
+    )
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+
+    type Input<'a> = Races;
+    type Config = ();
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Races, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Races, _: &()) -> i64 {
+        part1(&input.races)
+    }
+
+    fn part2(input: &Races, _: &()) -> i64 {
+        part2(input.race)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day06() {
+    let input = "\
+Time:      7  15   30
+Distance:  9  40  200";
+    assert_eq!(part1(&parse1(input).unwrap()), 288);
+    assert_eq!(part2(parse2(input).unwrap()), 71503);
+}
+
+#[test]
+fn test_day06_reference() {
+    use crate::property;
+
+    // tries every time the button can be held
+    fn ways(time: i64, record: i64) -> i64 {
+        (0..=time)
+            .filter(|hold| hold * (time - hold) > record)
+            .count() as i64
+    }
+
+    let races = |rng: &mut StdRng| {
+        let (mut times, mut records) = (String::new(), String::new());
+
+        for _ in 0..rng.gen_range(1..=2) {
+            // records on or next to a reachable distance are where the
+            // rounding of the quadratic formula matters
+            let time = rng.gen_range(0..60);
+            let hold = rng.gen_range(0..=time);
+            let record = (hold * (time - hold) - rng.gen_range(0..2)).max(0);
+
+            times += &format!(" {time}");
+            records += &format!(" {record}");
+        }
+
+        format!("Time:{times}\nDistance:{records}\n")
+    };
+
+    let result = property::check(
+        500,
+        races,
+        |text| property::smaller_numbers(text),
+        |text| {
+            let Ok(races) = parse(text) else {
+                return Ok(());
+            };
+            let reference = races.races.iter().map(|&(t, r)| ways(t, r)).product();
+
+            property::compare("part 1", part1(&races.races), reference)?;
+            property::compare(
+                "part 2",
+                part2(races.race),
+                ways(races.race.0, races.race.1),
+            )
+        },
+    );
+
+    if let Err(failure) = result {
+        panic!("{failure}");
+    }
+}
@@ -0,0 +1,230 @@
+use std::cmp::Ordering;
+use std::fmt::Write;
+
+use ahash::AHashMap;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::parse::{Cursor, ParseError};
+use crate::Solution;
+
+/// Strength of a card for part 1 and its label.
+pub type Card = (u32, char);
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum CardType {
+    HighCard,
+    OnePair,
+    TwoPairs,
+    Three,
+    FullHouse,
+    Four,
+    Five,
+}
+
+fn parse_cards(input: &str, token: &str) -> Result<[Card; 5], ParseError> {
+    let mut cards = [(0, '_'); 5];
+
+    if token.chars().count() != cards.len() {
+        return Err(ParseError::at(input, token, "five cards"));
+    }
+
+    for (card, (i, ch)) in cards.iter_mut().zip(token.char_indices()) {
+        *card = match ch {
+            ch if ch.is_ascii_digit() && ch != '0' && ch != '1' => (ch.to_digit(10).unwrap(), ch),
+            'T' => (10, 'T'),
+            'J' => (11, 'J'),
+            'Q' => (12, 'Q'),
+            'K' => (13, 'K'),
+            'A' => (14, 'A'),
+            _ => {
+                let card = &token[i..i + ch.len_utf8()];
+                return Err(ParseError::at(input, card, "card (2-9, T, J, Q, K, A)"));
+            }
+        };
+    }
+
+    Ok(cards)
+}
+
+fn parse(input: &str) -> Result<Vec<([Card; 5], usize)>, ParseError> {
+    Cursor::new(input).lines(|c| {
+        let cards = c.word()?;
+        let bid = c.uint()?;
+
+        Ok((parse_cards(input, cards)?, bid))
+    })
+}
+
+fn card_type(cards: &[Card]) -> CardType {
+    let mut counts = AHashMap::new();
+
+    for card in cards {
+        *counts.entry(card).or_insert(0) += 1;
+    }
+
+    let same_cards = if let Some(same) = counts.iter().map(|e| e.1).max() {
+        *same
+    } else {
+        return CardType::HighCard;
+    };
+
+    if same_cards == 5 {
+        CardType::Five
+    } else if same_cards == 4 {
+        CardType::Four
+    } else if same_cards == 3 {
+        if counts.iter().filter(|e| *e.1 == 2).count() == 1 {
+            CardType::FullHouse
+        } else {
+            CardType::Three
+        }
+    } else if same_cards == 2 {
+        if counts.iter().filter(|e| *e.1 == 2).count() == 2 {
+            CardType::TwoPairs
+        } else {
+            CardType::OnePair
+        }
+    } else {
+        CardType::HighCard
+    }
+}
+
+fn total_winnings(hands: Vec<([(u32, char); 5], usize)>) -> usize {
+    hands
+        .iter()
+        .enumerate()
+        .map(|(rank, h)| (rank + 1) * h.1)
+        .sum()
+}
+
+fn part1(mut hands: Vec<([Card; 5], usize)>) -> usize {
+    hands.sort_by(|(left, _), (right, _)| {
+        let type_left = card_type(left);
+        let type_right = card_type(right);
+
+        if type_left == type_right {
+            left.cmp(right)
+        } else {
+            type_left.cmp(&type_right)
+        }
+    });
+
+    total_winnings(hands)
+}
+
+fn part2(mut hands: Vec<([Card; 5], usize)>) -> usize {
+    hands.sort_by(|(left, _), (right, _)| {
+        let no_joker_left: Vec<_> = left.iter().filter(|c| c.1 != 'J').copied().collect();
+        let no_joker_right: Vec<_> = right.iter().filter(|c| c.1 != 'J').copied().collect();
+        let mut type_left = card_type(no_joker_left.as_slice());
+        let mut type_right = card_type(no_joker_right.as_slice());
+
+        type_left = use_jokers(type_left, left.len() - no_joker_left.len());
+        type_right = use_jokers(type_right, right.len() - no_joker_right.len());
+
+        if type_left == type_right {
+            for (left, right) in left.iter().zip(right.iter()) {
+                let ord = left.cmp(right);
+                if ord != Ordering::Equal {
+                    return if left.1 == 'J' {
+                        Ordering::Less
+                    } else if right.1 == 'J' {
+                        Ordering::Greater
+                    } else {
+                        ord
+                    };
+                }
+            }
+
+            Ordering::Equal
+        } else {
+            type_left.cmp(&type_right)
+        }
+    });
+
+    total_winnings(hands)
+}
+
+fn use_jokers(tl: CardType, num_jokers: usize) -> CardType {
+    match (tl, num_jokers) {
+        (CardType::Five, _) => CardType::Five,
+        (CardType::Four, 1) => CardType::Five,
+        (CardType::FullHouse, _) => CardType::FullHouse,
+        (CardType::Three, 1) => CardType::Four,
+        (CardType::Three, 2) => CardType::Five,
+        (CardType::TwoPairs, 1) => CardType::FullHouse,
+        (CardType::OnePair, 3) => CardType::Five,
+        (CardType::OnePair, 2) => CardType::Four,
+        (CardType::OnePair, 1) => CardType::Three,
+        (CardType::HighCard, 1) => CardType::OnePair,
+        (CardType::HighCard, 2) => CardType::Three,
+        (CardType::HighCard, 3) => CardType::Four,
+        (CardType::HighCard, 4) => CardType::Five,
+        (CardType::HighCard, 5) => CardType::Five,
+        (t, 0) => t,
+        _ => {
+            unreachable!()
+        }
+    }
+}
+
+/// `size` hands with their bids.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    const CARDS: &[u8] = b"23456789TJQKA";
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let hand: String = (0..5)
+            .map(|_| char::from(*CARDS.choose(rng).unwrap()))
+            .collect();
+        writeln!(input, "{hand} {}", rng.gen_range(1..=1000)).unwrap();
+    }
+
+    input
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+
+    type Input<'a> = Vec<([Card; 5], usize)>;
+    type Config = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<([Card; 5], usize)>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<([Card; 5], usize)>, _: &()) -> usize {
+        part1(input.clone())
+    }
+
+    fn part2(input: &Vec<([Card; 5], usize)>, _: &()) -> usize {
+        part2(input.clone())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day07() {
+    let input = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(input.clone()), 6440);
+    assert_eq!(part2(input), 5905);
+
+    let err = parse("32T3K 765\nT55X5 684").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 4));
+}
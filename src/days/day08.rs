@@ -0,0 +1,262 @@
+use ahash::AHashMap;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::generate;
+use crate::math::lcm;
+use crate::parse::{Cursor, ParseError};
+use crate::validate::Assumption;
+use crate::Solution;
+
+/// Left/right instructions and the left and right neighbour of every node.
+#[derive(Debug)]
+pub struct Network<'a> {
+    instructions: Vec<char>,
+    nodes: AHashMap<&'a str, (&'a str, &'a str)>,
+}
+
+fn parse(input: &str) -> Result<Network<'_>, ParseError> {
+    let mut c = Cursor::new(input);
+
+    let mut first = c.block();
+    let instructions = first.token(|d| d == 'L' || d == 'R', "`L` or `R`")?;
+    first.end()?;
+
+    let nodes = c.lines(|c| {
+        let curr = c.ident()?;
+        c.tag("=")?;
+        c.tag("(")?;
+        let left = c.ident()?;
+        c.tag(",")?;
+        let right = c.ident()?;
+        c.tag(")")?;
+
+        Ok((curr, (left, right)))
+    })?;
+
+    Ok(Network {
+        instructions: instructions.chars().collect(),
+        nodes: nodes.into_iter().collect(),
+    })
+}
+
+fn part1(network: &Network) -> usize {
+    let mut current = "AAA";
+    let mut step = 0;
+
+    for instr in network.instructions.iter().cycle() {
+        let (left, right) = network.nodes.get(current).unwrap();
+        match instr {
+            'L' => current = left,
+            'R' => current = right,
+            _ => (),
+        };
+
+        step += 1;
+
+        if current == "ZZZ" {
+            break;
+        }
+    }
+
+    step
+}
+
+fn part2(network: &Network) -> usize {
+    let mut current: Vec<_> = network.nodes.keys().filter(|n| n.ends_with('A')).collect();
+
+    let mut first_dests = vec![];
+
+    for (step, instr) in network.instructions.iter().cycle().enumerate() {
+        for node in &mut current {
+            if node.ends_with('Z') {
+                first_dests.push(step);
+            }
+            let (left, right) = network.nodes.get(*node).unwrap();
+            match instr {
+                'L' => *node = left,
+                'R' => *node = right,
+                _ => (),
+            };
+        }
+
+        if first_dests.len() == current.len() {
+            break;
+        }
+    }
+
+    first_dests
+        .iter()
+        .try_fold(1, |a, b| lcm(a, *b))
+        .expect("lcm of the cycle lengths overflows")
+}
+
+fn validate<'a>(network: &Network<'a>) -> Vec<Assumption> {
+    let undefined = network
+        .nodes
+        .values()
+        .flat_map(|&(left, right)| [left, right])
+        .find(|node| !network.nodes.contains_key(node));
+
+    // steps until the ghost first stands on a node ending with `Z` after
+    // `step`, the state space bounds the search
+    let limit = network.nodes.len() * network.instructions.len() + 1;
+    let walk = |mut node: &'a str, mut step: usize| -> Option<(usize, &'a str)> {
+        for _ in 0..limit {
+            let (left, right) = network.nodes[node];
+            node = match network.instructions[step % network.instructions.len()] {
+                'L' => left,
+                _ => right,
+            };
+            step += 1;
+
+            if node.ends_with('Z') {
+                return Some((step, node));
+            }
+        }
+        None
+    };
+
+    let mut cycles = Ok(());
+    let starts = network.nodes.keys().filter(|n| n.ends_with('A'));
+    for &start in starts.take_while(|_| undefined.is_none()) {
+        let Some((first, end)) = walk(start, 0) else {
+            cycles = Err(format!("the ghost from {start} never reaches a Z node"));
+            break;
+        };
+        match walk(end, first) {
+            Some((second, again)) if again == end && second == 2 * first => (),
+            Some((second, again)) => {
+                cycles = Err(format!(
+                    "the ghost from {start} reaches {end} after {first} steps, \
+                     then {again} after {} more",
+                    second - first
+                ));
+                break;
+            }
+            None => {
+                cycles = Err(format!(
+                    "the ghost from {start} reaches no Z node after {end}"
+                ));
+                break;
+            }
+        }
+    }
+
+    vec![
+        Assumption::check(
+            "the nodes AAA and ZZZ exist",
+            network.nodes.contains_key("AAA") && network.nodes.contains_key("ZZZ"),
+        ),
+        Assumption::new(
+            "every node leads to defined nodes",
+            undefined.map_or(Ok(()), |node| Err(format!("{node} is not defined"))),
+        ),
+        Assumption::new(
+            "every ghost reaches the same Z node again after as many steps as it took to reach it first",
+            cycles,
+        ),
+    ]
+}
+
+/// `size` instructions and six ghosts, each on a loop of `size` times a prime
+/// steps from its start to its end node and around again.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    const PRIMES: [usize; 9] = [43, 47, 53, 59, 61, 67, 71, 73, 79];
+    const ALPHABET: &str = "BCDEFGHIJKLMNOPQRSTUVWXY";
+    let size = size.max(1);
+
+    let instructions: String = (0..size)
+        .map(|_| if rng.gen() { 'L' } else { 'R' })
+        .collect();
+    let loops: Vec<_> = PRIMES.choose_multiple(rng, 6).map(|p| p * size).collect();
+
+    // only the start and end nodes end with `A` and `Z`
+    let inner = loops.iter().map(|l| l - 1).sum();
+    let mut names =
+        generate::names(rng, inner, generate::name_len(inner, 24).max(3), ALPHABET).into_iter();
+    let mut ghosts = vec![("AAA".to_string(), "ZZZ".to_string())];
+    for prefix in generate::names(rng, loops.len() - 1, 2, ALPHABET) {
+        ghosts.push((prefix.clone() + "A", prefix + "Z"));
+    }
+
+    let mut nodes = vec![];
+    for (length, (start, end)) in loops.iter().zip(ghosts) {
+        let ring: Vec<_> = names.by_ref().take(length - 1).collect();
+
+        // start -> ring[0] -> ... -> end -> ring[0], whatever the direction
+        nodes.push(format!("{start} = ({0}, {0})", ring[0]));
+        for pair in ring.windows(2) {
+            nodes.push(format!("{} = ({1}, {1})", pair[0], pair[1]));
+        }
+        nodes.push(format!("{} = ({end}, {end})", ring[ring.len() - 1]));
+        nodes.push(format!("{end} = ({0}, {0})", ring[0]));
+    }
+    nodes.shuffle(rng);
+
+    format!("{instructions}\n\n{}\n", nodes.join("\n"))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+
+    type Input<'a> = Network<'a>;
+    type Config = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Network<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Network<'_>, _: &()) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Network<'_>, _: &()) -> usize {
+        part2(input)
+    }
+
+    fn validate(input: &Network<'_>, _: &()) -> Vec<Assumption> {
+        validate(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day08() {
+    let input = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 6);
+    // ZZZ leads back to itself, so part 2 does not apply
+    let holds: Vec<_> = validate(&input).iter().map(Assumption::holds).collect();
+    assert_eq!(holds, [true, true, false]);
+
+    let input = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+    let input = parse(input).unwrap();
+    assert_eq!(part2(&input), 6);
+    let holds: Vec<_> = validate(&input).iter().map(Assumption::holds).collect();
+    assert_eq!(holds, [false, true, true]);
+}
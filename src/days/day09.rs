@@ -0,0 +1,107 @@
+use std::fmt::Write;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::parse::{Cursor, ParseError};
+use crate::Solution;
+
+fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    Cursor::new(input).lines(|c| c.many(Cursor::int))
+}
+
+fn calc_diffs(history: &[i64]) -> Vec<Vec<i64>> {
+    let mut diffs = vec![history.to_vec()];
+    let mut next = vec![];
+    loop {
+        for win in diffs.last().unwrap().windows(2) {
+            next.push(win[1] - win[0]);
+        }
+
+        if next.iter().all(|n| *n == 0) {
+            break;
+        }
+
+        diffs.push(next);
+        next = vec![];
+    }
+    diffs
+}
+
+fn part1(histories: &[Vec<i64>]) -> i64 {
+    histories
+        .iter()
+        .map(|history| {
+            calc_diffs(history)
+                .iter()
+                .fold(0, |acc, d| d.last().unwrap() + acc)
+        })
+        .sum()
+}
+
+fn part2(histories: &[Vec<i64>]) -> i64 {
+    histories
+        .iter()
+        .map(|history| calc_diffs(history).iter().rfold(0, |acc, d| d[0] - acc))
+        .sum()
+}
+
+/// `size` sequences of 21 values of a random polynomial of degree up to 5.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let coefficients: Vec<i64> = (0..rng.gen_range(1..=6))
+            .map(|_| rng.gen_range(-5..=5))
+            .collect();
+        let values: Vec<_> = (0..21i64)
+            .map(|x| {
+                let value = coefficients.iter().rev().fold(0, |acc, c| acc * x + c);
+                value.to_string()
+            })
+            .collect();
+
+        writeln!(input, "{}", values.join(" ")).unwrap();
+    }
+
+    input
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+
+    type Input<'a> = Vec<Vec<i64>>;
+    type Config = ();
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Vec<i64>>, _: &()) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Vec<i64>>, _: &()) -> i64 {
+        part2(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day09() {
+    let input = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 114);
+    assert_eq!(part2(&input), 2);
+}
@@ -0,0 +1,220 @@
+use ahash::AHashSet;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::generate;
+use crate::geometry;
+use crate::grid::{Direction, Grid, Point};
+use crate::parse::{Cursor, ParseError};
+use crate::render::{Canvas, Color};
+use crate::Solution;
+
+/// Pipe map with the position of the animal.
+#[derive(Debug)]
+pub struct Maze {
+    start: Point,
+    tiles: Grid<char>,
+}
+
+fn parse(input: &str) -> Result<Maze, ParseError> {
+    let mut c = Cursor::new(input);
+    let tiles = c.grid("pipe", |t| "|-LJ7F.S".contains(t).then_some(t))?;
+
+    let Some(start) = tiles.position(|&t| t == 'S') else {
+        return Err(c.error("start tile `S`"));
+    };
+
+    Ok(Maze { tiles, start })
+}
+
+fn connects_dir(dir: Direction, pipe: char) -> bool {
+    let north_open = ['|', 'L', 'J'];
+    let east_open = ['-', 'L', 'F'];
+    let south_open = ['|', 'F', '7'];
+    let west_open = ['-', 'J', '7'];
+
+    if pipe == 'S' {
+        return true;
+    }
+
+    match dir {
+        Direction::North => north_open.contains(&pipe),
+        Direction::South => south_open.contains(&pipe),
+        Direction::East => east_open.contains(&pipe),
+        Direction::West => west_open.contains(&pipe),
+    }
+}
+
+fn step(maze: &Maze, curr: Point, visited: &mut AHashSet<Point>) -> Option<Point> {
+    let curr_tile = maze.tiles[curr];
+
+    for dir in Direction::ALL {
+        let pos = curr.step(dir);
+
+        if let Some(&tile) = maze.tiles.get(pos) {
+            if visited.contains(&pos) {
+                continue;
+            }
+
+            if connects_dir(dir, curr_tile) && connects_dir(dir.reverse(), tile) {
+                visited.insert(pos);
+                return Some(pos);
+            }
+        }
+    }
+
+    None
+}
+
+fn main_loop(maze: &Maze) -> Vec<Point> {
+    let mut pos = maze.start;
+    let mut visited = AHashSet::new();
+    let mut tiles = vec![pos];
+
+    visited.insert(pos);
+
+    while let Some(next_pos) = step(maze, pos, &mut visited) {
+        pos = next_pos;
+        tiles.push(pos);
+    }
+
+    tiles
+}
+
+fn part1(maze: &Maze) -> usize {
+    main_loop(maze).len() / 2
+}
+
+fn part2(maze: &Maze) -> i64 {
+    // every loop tile is a vertex, so the tiles enclosed by the loop are the
+    // lattice points inside the polygon
+    geometry::interior_points(&main_loop(maze))
+}
+
+fn render(maze: &Maze) -> Canvas {
+    let main_loop = main_loop(maze);
+    let on_loop: AHashSet<_> = main_loop.iter().copied().collect();
+    let mut canvas = Canvas::new(&maze.tiles, |&t| match t {
+        '.' => Color::BLACK,
+        _ => Color::DARK_GRAY,
+    });
+
+    for pos in maze.tiles.points() {
+        if !on_loop.contains(&pos) && geometry::contains(&main_loop, pos) {
+            canvas.fill(pos, Color::GREEN);
+        }
+    }
+
+    canvas.fill(maze.start, Color::RED);
+    canvas.path(
+        main_loop.iter().chain([&maze.start]).copied(),
+        Color::YELLOW,
+    );
+
+    canvas
+}
+
+/// Maze of about `size` x `size` tiles whose loop winds around a random
+/// tree, surrounded by unconnected pipes.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let nodes = (size.saturating_sub(1) / 4).max(2);
+    let corners = generate::outline(&generate::spanning_tree(rng, nodes));
+    let side = 4 * nodes + 1;
+
+    // the outline at twice the scale, so that the tree tiles are enclosed
+    let path: Vec<_> = corners
+        .iter()
+        .zip(corners.iter().cycle().skip(1))
+        .flat_map(|(&a, &b)| [a * 2, a + b])
+        .map(|p| p + Point::new(1, 1))
+        .collect();
+
+    let mut tiles = Grid::filled(side, side, '.');
+    for pos in tiles.points().collect::<Vec<_>>() {
+        tiles[pos] = *b"|-LJ7F..".choose(rng).unwrap() as char;
+    }
+
+    for (i, &pos) in path.iter().enumerate() {
+        let prev = path[(i + path.len() - 1) % path.len()];
+        let next = path[(i + 1) % path.len()];
+        tiles[pos] = match (prev - pos, next - pos) {
+            (a, b) if a.x == 0 && b.x == 0 => '|',
+            (a, b) if a.y == 0 && b.y == 0 => '-',
+            (a, b) if a.y + b.y < 0 && a.x + b.x > 0 => 'L',
+            (a, b) if a.y + b.y < 0 => 'J',
+            (a, b) if a.x + b.x < 0 => '7',
+            _ => 'F',
+        };
+    }
+
+    // no pipe next to the start may connect to it except the loop
+    let start = path[rng.gen_range(0..path.len())];
+    tiles[start] = 'S';
+    for neighbor in tiles.neighbors4(start).collect::<Vec<_>>() {
+        if !path.contains(&neighbor) {
+            tiles[neighbor] = '.';
+        }
+    }
+
+    generate::grid_text(&tiles)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Input<'a> = Maze;
+    type Config = ();
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Maze, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Maze, _: &()) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Maze, _: &()) -> i64 {
+        part2(input)
+    }
+
+    fn render(input: &Maze, _: &()) -> Option<Canvas> {
+        Some(render(input))
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day10() {
+    let input = "\
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 8);
+
+    let input = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part2(&input), 8);
+}
@@ -0,0 +1,276 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::generate;
+use crate::grid::{Grid, Point};
+use crate::parse::{Cursor, ParseError};
+use crate::render::{Canvas, Color};
+use crate::{param, Solution};
+
+/// Galaxy positions and the rows and columns without galaxies, which
+/// expand.
+#[derive(Debug)]
+pub struct Universe {
+    width: usize,
+    height: usize,
+    galaxies: Vec<(usize, usize)>,
+    empty_rows: Vec<usize>,
+    empty_cols: Vec<usize>,
+}
+
+fn parse(input: &str) -> Result<Universe, ParseError> {
+    let tiles = Cursor::new(input).grid("`.` or `#`", |t| matches!(t, '.' | '#').then_some(t))?;
+
+    let empty_rows = (0..tiles.height())
+        .filter(|&y| tiles.row(y).iter().all(|&t| t == '.'))
+        .collect();
+
+    let empty_cols = (0..tiles.width())
+        .filter(|&x| tiles.column(x).all(|&t| t == '.'))
+        .collect();
+
+    let galaxies = tiles
+        .iter()
+        .filter(|(_, &tile)| tile != '.')
+        .map(|(pos, _)| (pos.x as usize, pos.y as usize))
+        .collect();
+
+    Ok(Universe {
+        width: tiles.width(),
+        height: tiles.height(),
+        galaxies,
+        empty_rows,
+        empty_cols,
+    })
+}
+
+pub struct Config {
+    /// Every empty row and column is replaced by this many empty rows or
+    /// columns in part 2.
+    pub expansion: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { expansion: 1000000 }
+    }
+}
+
+fn solve(puzzle: &Universe, expansion: usize) -> usize {
+    let mut galaxies = puzzle.galaxies.clone();
+    for (galaxy_x, galaxy_y) in &mut galaxies {
+        for row in puzzle.empty_rows.iter().rev() {
+            if *galaxy_y > *row {
+                *galaxy_y += expansion;
+            }
+        }
+        for col in puzzle.empty_cols.iter().rev() {
+            if *galaxy_x > *col {
+                *galaxy_x += expansion;
+            }
+        }
+    }
+
+    let mut sum = 0;
+    for from in galaxies.iter() {
+        for to in galaxies.iter() {
+            if from < to {
+                let diff = from.0.abs_diff(to.0) + from.1.abs_diff(to.1);
+
+                sum += diff
+            }
+        }
+    }
+
+    sum
+}
+
+fn part1(universe: &Universe) -> usize {
+    solve(universe, 1)
+}
+
+fn part2(universe: &Universe, expansion: usize) -> usize {
+    solve(universe, expansion - 1)
+}
+
+/// The image after the expansion of part 1, the doubled empty rows and
+/// columns in gray and the galaxies in yellow.
+fn render(universe: &Universe) -> Canvas {
+    // position of line `i` after the expansion
+    let expand = |empty: &[usize], i: usize| i + empty.iter().filter(|&&e| e < i).count();
+    let doubled = |empty: &[usize], len: usize| {
+        let mut doubled = vec![false; expand(empty, len)];
+        for &e in empty {
+            doubled[expand(empty, e)] = true;
+            doubled[expand(empty, e) + 1] = true;
+        }
+        doubled
+    };
+    let columns = doubled(&universe.empty_cols, universe.width);
+    let rows = doubled(&universe.empty_rows, universe.height);
+
+    let image = Grid::filled(columns.len(), rows.len(), ());
+    let mut canvas = Canvas::new(&image, |_| Color::BLACK);
+    for pos in image.points() {
+        if columns[pos.x as usize] || rows[pos.y as usize] {
+            canvas.fill(pos, Color::DARK_GRAY);
+        }
+    }
+    for &(x, y) in &universe.galaxies {
+        let pos = Point::new(
+            expand(&universe.empty_cols, x) as i64,
+            expand(&universe.empty_rows, y) as i64,
+        );
+        canvas.fill(pos, Color::YELLOW);
+    }
+
+    canvas
+}
+
+/// Image of `size` x `size` pixels with a few empty rows and columns.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+    let empty_columns: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+    let mut image = Grid::filled(size, size, '.');
+
+    for pos in image.points().collect::<Vec<_>>() {
+        let empty = empty_columns[pos.x as usize] || empty_rows[pos.y as usize];
+        if !empty && rng.gen_bool(0.05) {
+            image[pos] = '#';
+        }
+    }
+
+    generate::grid_text(&image)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    type Input<'a> = Universe;
+    type Config = Config;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Universe, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Universe, _: &Config) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Universe, config: &Config) -> usize {
+        part2(input, config.expansion)
+    }
+
+    fn render(input: &Universe, _: &Config) -> Option<Canvas> {
+        Some(render(input))
+    }
+
+    fn configure(config: &mut Config, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "expansion" => config.expansion = param(key, value)?,
+            _ => return Err(format!("unknown parameter {key}")),
+        }
+        Ok(())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day11() {
+    let input = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 374);
+    assert_eq!(solve(&input, 99), 8410);
+}
+
+#[test]
+fn test_day11_reference() {
+    use crate::property;
+
+    // repeats every empty row and column `copies` times and measures on the
+    // expanded image
+    fn distances(text: &str, copies: usize) -> usize {
+        let rows: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+        let empty = |line: &[char]| line.iter().all(|&t| t == '.');
+
+        let mut expanded = vec![];
+        for row in &rows {
+            let count = if empty(row) { copies } else { 1 };
+            expanded.extend(std::iter::repeat_n(row.clone(), count));
+        }
+        let mut columns = vec![];
+        for x in 0..rows[0].len() {
+            let column: Vec<_> = expanded.iter().map(|row| row[x]).collect();
+            let count = if empty(&column) { copies } else { 1 };
+            columns.extend(std::iter::repeat_n(column, count));
+        }
+
+        let mut galaxies = vec![];
+        for (x, column) in columns.iter().enumerate() {
+            for (y, &tile) in column.iter().enumerate() {
+                if tile == '#' {
+                    galaxies.push((x, y));
+                }
+            }
+        }
+
+        let mut sum = 0;
+        for (i, a) in galaxies.iter().enumerate() {
+            for b in &galaxies[i + 1..] {
+                sum += a.0.abs_diff(b.0) + a.1.abs_diff(b.1);
+            }
+        }
+        sum
+    }
+
+    let result = property::check(
+        300,
+        |rng| {
+            let size = rng.gen_range(1..=12);
+            generate(rng, size)
+        },
+        |text| [property::remove_lines(text), property::remove_columns(text)].concat(),
+        |text| {
+            let Ok(universe) = parse(text) else {
+                return Ok(());
+            };
+
+            property::compare("part 1", part1(&universe), distances(text, 2))?;
+            property::compare("part 2", part2(&universe, 10), distances(text, 10))
+        },
+    );
+
+    if let Err(failure) = result {
+        panic!("{failure}");
+    }
+}
+
+#[test]
+fn test_day11_render() {
+    let input = parse(include_str!("../../examples/day11/example.txt")).unwrap();
+
+    // the expanded image of the puzzle description
+    let image = render(&input).to_image(1);
+    assert_eq!((image.width, image.height), (13, 12));
+    assert_eq!(image.pixels[4], Color::YELLOW);
+    assert_eq!(image.pixels[13 * 3], Color::DARK_GRAY);
+    assert_eq!(image.pixels[13 * 11 + 5], Color::YELLOW);
+}
@@ -0,0 +1,217 @@
+use std::fmt::Write;
+
+use ahash::AHashMap;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rayon::prelude::*;
+
+use crate::parse::{Cursor, ParseError};
+use crate::Solution;
+
+/// A row of springs (`.` operational, `#` damaged, `?` unknown) and the
+/// sizes of its groups of damaged springs.
+#[derive(Debug)]
+pub struct Springs {
+    row: Vec<char>,
+    condition: Vec<i64>,
+}
+
+fn parse(input: &str) -> Result<Vec<Springs>, ParseError> {
+    Cursor::new(input).lines(|c| {
+        let row = c.token(|t| matches!(t, '.' | '#' | '?'), "springs")?;
+        let condition = c.separated(",", Cursor::uint)?;
+
+        Ok(Springs {
+            row: row.chars().collect(),
+            condition,
+        })
+    })
+}
+
+fn prefix_valid<'a, 'b>(
+    prefix: &'a mut [char],
+    cond: &'b [i64],
+) -> Option<(&'a mut [char], &'b [i64])> {
+    let mut num = 0;
+    for (n, c) in prefix.iter().enumerate().skip_while(|(_, &c)| c == '.') {
+        match c {
+            '#' => num += 1,
+            '.' => {
+                if cond.first() == Some(&num) {
+                    return Some((&mut prefix[n..], &cond[1..]));
+                } else {
+                    return None;
+                }
+            }
+            '?' => {
+                if let Some(c) = cond.first() {
+                    if *c >= num {
+                        return Some((prefix, cond));
+                    } else {
+                        return None;
+                    }
+                } else {
+                    return Some((prefix, cond));
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    if let Some(c) = cond.first() {
+        if *c == num {
+            Some((prefix, cond))
+        } else {
+            None
+        }
+    } else {
+        Some((&mut [], &[]))
+    }
+}
+
+fn arrangements(
+    cache: &mut AHashMap<(String, Vec<i64>), i64>,
+    row: &mut [char],
+    cond: &[i64],
+) -> i64 {
+    let key = (row.iter().collect::<String>(), cond.to_vec());
+
+    if let Some(v) = cache.get(&key) {
+        return *v;
+    }
+
+    let Some((rest, cond_rest)) = prefix_valid(row, cond) else {
+        return 0;
+    };
+
+    for i in 0..rest.len() {
+        if rest[i] == '?' {
+            rest[i] = '#';
+            let mut sum = arrangements(cache, rest, cond_rest);
+            rest[i] = '.';
+            sum += arrangements(cache, rest, cond_rest);
+            rest[i] = '?';
+
+            cache.insert(key, sum);
+
+            return sum;
+        }
+    }
+
+    let nums: Vec<i64> = row
+        .split(|c| *c == '.')
+        .filter(|w| !w.is_empty())
+        .map(|w| w.len() as i64)
+        .collect();
+
+    let res = if nums == cond { 1 } else { 0 };
+
+    cache.insert(key, res);
+
+    res
+}
+
+impl Springs {
+    /// Number of ways to replace the unknown springs so that the groups
+    /// match.
+    pub fn arrangements(&self) -> i64 {
+        arrangements(&mut AHashMap::new(), &mut self.row.clone(), &self.condition)
+    }
+
+    /// The row repeated `copies` times with an unknown spring in between, and
+    /// the groups repeated as often.
+    pub fn unfold(&self, copies: usize) -> Springs {
+        Springs {
+            row: vec![&self.row[..]; copies].join(&'?'),
+            condition: self.condition.repeat(copies),
+        }
+    }
+}
+
+fn part1(puzzle: &[Springs]) -> i64 {
+    puzzle.iter().map(Springs::arrangements).sum()
+}
+
+fn part2(puzzle: &[Springs]) -> i64 {
+    puzzle.par_iter().map(|p| p.unfold(5).arrangements()).sum()
+}
+
+/// `size` rows of up to 20 springs, the groups taken from an actual
+/// arrangement so that every row has at least one.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let mut springs: Vec<u8> = (0..rng.gen_range(1..=20))
+            .map(|_| if rng.gen_bool(0.4) { b'#' } else { b'.' })
+            .collect();
+        if !springs.contains(&b'#') {
+            let i = rng.gen_range(0..springs.len());
+            springs[i] = b'#';
+        }
+
+        let groups: Vec<_> = springs
+            .split(|&s| s == b'.')
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+        let springs: String = springs
+            .iter()
+            .map(|&s| {
+                if rng.gen_bool(0.5) {
+                    '?'
+                } else {
+                    char::from(s)
+                }
+            })
+            .collect();
+
+        writeln!(input, "{springs} {}", groups.join(",")).unwrap();
+    }
+
+    input
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+
+    type Input<'a> = Vec<Springs>;
+    type Config = ();
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Springs>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Springs>, _: &()) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Springs>, _: &()) -> i64 {
+        part2(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day12() {
+    let input = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 21);
+    assert_eq!(part2(&input), 525152);
+    assert_eq!(input[5].arrangements(), 10);
+    assert_eq!(input[5].unfold(5).arrangements(), 506250);
+}
@@ -0,0 +1,302 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::generate;
+use crate::grid::{Grid, Point};
+use crate::parse::{Cursor, ParseError};
+use crate::render::{Canvas, Color};
+use crate::Solution;
+
+/// A pattern of ash and rocks.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    tiles: Grid<char>,
+}
+
+fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    Cursor::new(input).blocks(|c| {
+        let start = c.rest();
+        let tiles = c.grid("`.` or `#`", |t| matches!(t, '.' | '#').then_some(t))?;
+
+        // part 2 needs a line of reflection that is off by one tile
+        let transposed = tiles.transpose();
+        let smudged = (1..tiles.width()).any(|line| mismatches(&tiles, line) == 1)
+            || (1..tiles.height()).any(|line| mismatches(&transposed, line) == 1);
+        if !smudged {
+            let first = start.lines().next().unwrap_or(start);
+            return Err(c.error_at(first, "pattern with a smudge"));
+        }
+
+        Ok(Pattern { tiles })
+    })
+}
+
+/// Columns left of vertical lines of reflection.
+fn horizontal_mirrors(pattern: &Pattern) -> Vec<i64> {
+    let width = pattern.tiles.width();
+
+    (1..width)
+        .filter(|&div| {
+            (0..div.min(width - div)).all(|dist| {
+                let left = pattern.tiles.column(div - 1 - dist);
+                left.eq(pattern.tiles.column(div + dist))
+            })
+        })
+        .map(|div| div as i64)
+        .collect()
+}
+
+/// Rows above horizontal lines of reflection.
+fn vertical_mirrors(pattern: &Pattern) -> Vec<i64> {
+    let height = pattern.tiles.height();
+
+    (1..height)
+        .filter(|&div| {
+            (0..div.min(height - div))
+                .all(|dist| pattern.tiles.row(div - 1 - dist) == pattern.tiles.row(div + dist))
+        })
+        .map(|div| div as i64)
+        .collect()
+}
+
+fn part1(puzzle: &[Pattern]) -> i64 {
+    puzzle
+        .iter()
+        .map(|p| {
+            horizontal_mirrors(p).first().copied().unwrap_or_default()
+                + 100 * vertical_mirrors(p).first().copied().unwrap_or_default()
+        })
+        .sum()
+}
+
+fn part2(puzzle: &[Pattern]) -> i64 {
+    puzzle
+        .iter()
+        .map(|p| {
+            let mut cleaned = p.clone();
+            let hori_dirt = horizontal_mirrors(p).first().copied();
+            let vert_dirt = vertical_mirrors(p).first().copied();
+
+            for pos in p.tiles.points() {
+                toggle_tile(&mut cleaned, pos);
+
+                let hori_clean = horizontal_mirrors(&cleaned)
+                    .iter()
+                    .find(|&&m| Some(m) != hori_dirt)
+                    .copied();
+
+                let vert_clean = vertical_mirrors(&cleaned)
+                    .iter()
+                    .find(|&&m| Some(m) != vert_dirt)
+                    .copied();
+
+                if vert_clean.is_some() || hori_clean.is_some() {
+                    return hori_clean.unwrap_or_default() + 100 * vert_clean.unwrap_or_default();
+                }
+
+                toggle_tile(&mut cleaned, pos)
+            }
+
+            unreachable!("parse checks for a smudge")
+        })
+        .sum()
+}
+
+fn toggle_tile(cleaned: &mut Pattern, pos: Point) {
+    if cleaned.tiles[pos] == '.' {
+        cleaned.tiles[pos] = '#';
+    } else {
+        cleaned.tiles[pos] = '.';
+    }
+}
+
+/// Number of tiles that differ when mirroring the columns left of `line`,
+/// or the rows above it for a transposed `tiles`.
+fn mismatches(tiles: &Grid<char>, line: usize) -> usize {
+    let span = line.min(tiles.width() - line);
+
+    (0..tiles.height())
+        .map(|y| {
+            let row = tiles.row(y);
+            (0..span)
+                .filter(|i| row[line - 1 - i] != row[line + i])
+                .count()
+        })
+        .sum()
+}
+
+/// The patterns below each other, the rows or columns next to the line of
+/// reflection of part 1 in blue.
+fn render(puzzle: &[Pattern]) -> Canvas {
+    let width = puzzle.iter().map(|p| p.tiles.width()).max().unwrap_or(0);
+    let height = puzzle.iter().map(|p| p.tiles.height() + 1).sum::<usize>();
+    let mut canvas = Canvas::new(&Grid::filled(width, height.saturating_sub(1), ()), |_| {
+        Color::DARK_GRAY
+    });
+    let mut top = 0;
+
+    for pattern in puzzle {
+        let column = horizontal_mirrors(pattern).first().copied();
+        let row = vertical_mirrors(pattern).first().copied();
+        let next_to = |mirror: Option<i64>, i: i64| mirror.is_some_and(|m| i == m - 1 || i == m);
+
+        for (pos, &tile) in pattern.tiles.iter() {
+            let mirror = next_to(column, pos.x) || next_to(row, pos.y);
+            let color = match (tile, mirror) {
+                ('#', false) => Color::GRAY,
+                ('#', true) => Color::BLUE,
+                (_, false) => Color::BLACK,
+                (_, true) => Color::BLUE.mix(Color::BLACK, 0.7),
+            };
+            canvas.fill(Point::new(pos.x, pos.y + top), color);
+        }
+
+        top += pattern.tiles.height() as i64 + 1;
+    }
+
+    canvas
+}
+
+/// Pattern with one perfect line of reflection and another one that is
+/// off by a single smudge, `None` if the random layout cannot hold both.
+fn smudged_pattern(rng: &mut StdRng) -> Option<Grid<char>> {
+    let (width, height) = (rng.gen_range(5..=17), rng.gen_range(5..=17));
+    let mut tiles = Grid::filled(width, height, '.');
+    for pos in tiles.points().collect::<Vec<_>>() {
+        if rng.gen() {
+            tiles[pos] = '#';
+        }
+    }
+
+    // mirror both ways, then smudge a tile in a row that the perfect
+    // horizontal line does not reflect, so only the vertical line breaks
+    let (column, row) = (rng.gen_range(1..width), rng.gen_range(1..height));
+    for y in 0..height {
+        for x in column..width.min(2 * column) {
+            let pos = Point::new(x as i64, y as i64);
+            tiles[pos] = tiles[Point::new((2 * column - 1 - x) as i64, y as i64)];
+        }
+    }
+    for y in row..height.min(2 * row) {
+        for x in 0..width {
+            let pos = Point::new(x as i64, y as i64);
+            tiles[pos] = tiles[Point::new(x as i64, (2 * row - 1 - y) as i64)];
+        }
+    }
+
+    let span = row.min(height - row);
+    let free: Vec<_> = (0..row - span).chain(row + span..height).collect();
+    let span = column.min(width - column);
+    let pos = Point::new(
+        rng.gen_range(column - span..column + span) as i64,
+        *free.choose(rng)? as i64,
+    );
+    tiles[pos] = if tiles[pos] == '#' { '.' } else { '#' };
+
+    let mut tiles = if rng.gen() { tiles.transpose() } else { tiles };
+    if rng.gen() {
+        tiles = tiles.rotate_cw().rotate_cw();
+    }
+
+    // exactly one line without and one line with a single mismatch
+    let transposed = tiles.transpose();
+    let counts: Vec<_> = (1..tiles.width())
+        .map(|line| mismatches(&tiles, line))
+        .chain((1..tiles.height()).map(|line| mismatches(&transposed, line)))
+        .collect();
+    let lines = |n| counts.iter().filter(|&&c| c == n).count();
+
+    (lines(0) == 1 && lines(1) == 1).then_some(tiles)
+}
+
+/// `size` patterns, each with a line of reflection and a smudge.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut patterns = vec![];
+
+    while patterns.len() < size {
+        if let Some(tiles) = smudged_pattern(rng) {
+            patterns.push(generate::grid_text(&tiles));
+        }
+    }
+
+    patterns.join("\n")
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    type Input<'a> = Vec<Pattern>;
+    type Config = ();
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Pattern>, _: &()) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Pattern>, _: &()) -> i64 {
+        part2(input)
+    }
+
+    fn render(input: &Vec<Pattern>, _: &()) -> Option<Canvas> {
+        Some(render(input))
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day13() {
+    let input = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 405);
+    assert_eq!(part2(&input), 400);
+}
+
+#[test]
+fn test_day13_errors() {
+    // the second pattern has no line that is off by one tile
+    let err = parse("#.#\n...\n\n#.\n.#\n").unwrap_err();
+    assert_eq!((err.line, err.column), (4, 1));
+    assert_eq!(err.expected, "pattern with a smudge");
+}
+
+#[test]
+fn test_day13_render() {
+    let input = parse(include_str!("../../examples/day13/example.txt")).unwrap();
+
+    // the mirror between columns 5 and 6 of the first pattern and the one
+    // between rows 4 and 5 of the second
+    let image = render(&input).to_image(1);
+    assert_eq!((image.width, image.height), (9, 15));
+    let pixel = |x: usize, y: usize| image.pixels[y * 9 + x];
+    assert_eq!(
+        [pixel(0, 0), pixel(4, 1), pixel(0, 7), pixel(0, 11)],
+        [Color::GRAY, Color::BLUE, Color::DARK_GRAY, Color::BLUE]
+    );
+}
@@ -0,0 +1,180 @@
+use std::ops::ControlFlow;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::animate::Frames;
+use crate::cycle;
+use crate::generate;
+use crate::grid::{Direction, Grid};
+use crate::parse::{Cursor, ParseError};
+use crate::render::{Canvas, Color};
+use crate::Solution;
+
+fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Cursor::new(input).grid("`.`, `#` or `O`", |t| {
+        matches!(t, '.' | '#' | 'O').then_some(t)
+    })
+}
+
+fn tilt(dish: &mut Grid<char>, dir: Direction) {
+    let mut rocks: Vec<_> = dish.points().filter(|&p| dish[p] == 'O').collect();
+
+    // move the rocks closest to the edge first
+    if matches!(dir, Direction::South | Direction::East) {
+        rocks.reverse();
+    }
+
+    for rock in rocks {
+        let mut curr = rock;
+        while dish.get(curr.step(dir)) == Some(&'.') {
+            curr = curr.step(dir);
+        }
+
+        dish[rock] = '.';
+        dish[curr] = 'O';
+    }
+}
+
+fn total_load(dish: &Grid<char>) -> i64 {
+    let height = dish.height() as i64;
+
+    dish.iter()
+        .filter(|(_, &tile)| tile == 'O')
+        .map(|(pos, _)| height - pos.y)
+        .sum()
+}
+
+fn part1(puzzle: &Grid<char>) -> i64 {
+    let mut dish = puzzle.clone();
+    tilt(&mut dish, Direction::North);
+    total_load(&dish)
+}
+
+/// Tilt directions of a spin cycle.
+const SPIN: [Direction; 4] = [
+    Direction::North,
+    Direction::West,
+    Direction::South,
+    Direction::East,
+];
+
+fn spin(dish: &Grid<char>) -> Grid<char> {
+    let mut dish = dish.clone();
+
+    for dir in SPIN {
+        tilt(&mut dish, dir);
+    }
+
+    dish
+}
+
+fn part2(puzzle: &Grid<char>) -> i64 {
+    // Brent keeps two dishes instead of every dish seen
+    let cycle = cycle::brent(puzzle.clone(), spin);
+
+    total_load(&cycle::iterate(
+        puzzle.clone(),
+        spin,
+        cycle.reduce(1000000000),
+    ))
+}
+
+fn canvas(dish: &Grid<char>) -> Canvas {
+    Canvas::new(dish, |&t| match t {
+        'O' => Color::ORANGE,
+        '#' => Color::GRAY,
+        _ => Color::BLACK,
+    })
+}
+
+/// The dish after the first spin cycle.
+fn render(puzzle: &Grid<char>) -> Canvas {
+    canvas(&spin(puzzle))
+}
+
+/// Every tilt until the spin cycles repeat.
+fn animate(puzzle: &Grid<char>, frames: &mut Frames) -> ControlFlow<()> {
+    let cycle = cycle::brent(puzzle.clone(), spin);
+    let mut dish = puzzle.clone();
+
+    frames(&canvas(&dish))?;
+
+    for _ in 0..cycle.prefix + cycle.period {
+        for dir in SPIN {
+            tilt(&mut dish, dir);
+            frames(&canvas(&dish))?;
+        }
+    }
+
+    ControlFlow::Continue(())
+}
+
+/// Platform of `size` x `size` tiles.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut platform = Grid::filled(size, size, '.');
+
+    for pos in platform.points().collect::<Vec<_>>() {
+        platform[pos] = match rng.gen_range(0..20) {
+            0..=3 => 'O',
+            4..=6 => '#',
+            _ => '.',
+        };
+    }
+
+    generate::grid_text(&platform)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+
+    type Input<'a> = Grid<char>;
+    type Config = ();
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Grid<char>, _: &()) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Grid<char>, _: &()) -> i64 {
+        part2(input)
+    }
+
+    fn render(input: &Grid<char>, _: &()) -> Option<Canvas> {
+        Some(render(input))
+    }
+
+    fn animate(input: &Grid<char>, _: &(), frames: &mut Frames) -> ControlFlow<()> {
+        animate(input, frames)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day14() {
+    let input = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 136);
+    assert_eq!(part2(&input), 64);
+}
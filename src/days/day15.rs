@@ -0,0 +1,153 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::parse::{Cursor, ParseError};
+use crate::Solution;
+
+/// What a step does with the lens labeled `label`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    /// `-`: takes the lens out of its box.
+    Remove,
+    /// `=` and a focal length: puts the lens into its box or replaces it.
+    Insert(usize),
+}
+
+/// One step of the initialization sequence, `text` being the whole step as
+/// hashed in part 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Step<'a> {
+    pub text: &'a str,
+    pub label: &'a str,
+    pub operation: Operation,
+}
+
+fn parse(input: &str) -> Result<Vec<Step<'_>>, ParseError> {
+    let mut c = Cursor::new(input);
+    let steps = c.separated(",", |c| {
+        let start = c.rest();
+        let label = c.token(|t| t.is_ascii_lowercase(), "label")?;
+        let operation = match () {
+            _ if c.try_tag("=") => Operation::Insert(c.uint()?),
+            _ if c.try_tag("-") => Operation::Remove,
+            _ => return Err(c.error("`=` or `-`")),
+        };
+        let text = &start[..start.len() - c.rest().len()];
+
+        Ok(Step {
+            text,
+            label,
+            operation,
+        })
+    })?;
+    c.end()?;
+
+    Ok(steps)
+}
+
+fn hash(input: &str) -> usize {
+    input
+        .chars()
+        .fold(0, |curr, c| ((curr + c as usize) * 17) % 256)
+}
+
+fn part1(puzzle: &[Step<'_>]) -> usize {
+    puzzle.iter().map(|step| hash(step.text)).sum()
+}
+
+fn part2(puzzle: &[Step<'_>]) -> usize {
+    const EMPTY: Vec<(&str, usize)> = vec![];
+    let mut map: [Vec<(&str, usize)>; 256] = [EMPTY; 256];
+
+    for step in puzzle {
+        let lbox = &mut map[hash(step.label)];
+        let pos = lbox.iter().position(|&(k, _)| k == step.label);
+
+        match (step.operation, pos) {
+            (Operation::Insert(value), Some(pos)) => lbox[pos].1 = value,
+            (Operation::Insert(value), None) => lbox.push((step.label, value)),
+            (Operation::Remove, Some(pos)) => {
+                lbox.remove(pos);
+            }
+            (Operation::Remove, None) => (),
+        }
+    }
+
+    map.iter()
+        .enumerate()
+        .filter(|(_, b)| !b.is_empty())
+        .map(|(box_nr, lbox)| {
+            lbox.iter()
+                .enumerate()
+                .map(|(slot, &(_, len))| (box_nr + 1) * (slot + 1) * len)
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+/// `size` steps on about a third as many labels.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let labels: Vec<String> = (0..(size / 3).max(1))
+        .map(|_| {
+            (0..rng.gen_range(2..=6))
+                .map(|_| char::from(rng.gen_range(b'a'..=b'z')))
+                .collect()
+        })
+        .collect();
+
+    let steps: Vec<_> = (0..size)
+        .map(|_| {
+            let label = labels.choose(rng).unwrap();
+            if rng.gen_bool(0.6) {
+                format!("{label}={}", rng.gen_range(1..=9))
+            } else {
+                format!("{label}-")
+            }
+        })
+        .collect();
+
+    steps.join(",") + "\n"
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    type Input<'a> = Vec<Step<'a>>;
+    type Config = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Step<'_>>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Step<'_>>, _: &()) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Step<'_>>, _: &()) -> usize {
+        part2(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day15() {
+    let input = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 1320);
+    assert_eq!(part2(&input), 145);
+
+    let err = parse("rn=1,cm\n").unwrap_err();
+    assert_eq!((err.line, err.column), (1, 8));
+    let err = parse("rn=x\n").unwrap_err();
+    assert_eq!((err.line, err.column), (1, 4));
+}
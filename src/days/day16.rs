@@ -0,0 +1,207 @@
+use std::ops::ControlFlow;
+
+use ahash::AHashSet;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rayon::prelude::*;
+
+use crate::animate::Frames;
+use crate::generate;
+use crate::grid::{Direction, Grid, Point};
+use crate::parse::{Cursor, ParseError};
+use crate::render::{Canvas, Color};
+use crate::Solution;
+
+fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Cursor::new(input).grid("`.`, `|`, `-`, `\\` or `/`", |t| {
+        matches!(t, '.' | '|' | '-' | '\\' | '/').then_some(t)
+    })
+}
+
+/// Tiles visited by the beam entering at `start`. All beams move one tile per
+/// step, `step` sees the energized tiles after each step and can stop the
+/// simulation early.
+fn energize<F>(tiles: &Grid<char>, start: (Direction, Point), mut step: F) -> AHashSet<Point>
+where
+    F: FnMut(&AHashSet<Point>) -> ControlFlow<()>,
+{
+    let mut rays = vec![start];
+    let mut energized = AHashSet::new();
+    let mut history = AHashSet::new();
+
+    while !rays.is_empty() {
+        for ray @ (mut ray_dir, ray_pos) in std::mem::take(&mut rays) {
+            if history.contains(&ray) {
+                continue;
+            }
+            history.insert(ray);
+
+            if let Some(tile) = tiles.get(ray_pos) {
+                let horizontal = ray_dir.is_horizontal();
+                energized.insert(ray_pos);
+
+                match tile {
+                    '|' if horizontal => {
+                        for dir in [Direction::South, Direction::North] {
+                            rays.push((dir, ray_pos.step(dir)));
+                        }
+                        continue;
+                    }
+                    '.' | '|' => (),
+                    '-' if horizontal => (),
+                    '-' => {
+                        for dir in [Direction::East, Direction::West] {
+                            rays.push((dir, ray_pos.step(dir)));
+                        }
+                        continue;
+                    }
+                    '\\' if horizontal => ray_dir = ray_dir.turn_right(),
+                    '\\' => ray_dir = ray_dir.turn_left(),
+                    '/' if horizontal => ray_dir = ray_dir.turn_left(),
+                    '/' => ray_dir = ray_dir.turn_right(),
+                    _ => unreachable!(),
+                }
+
+                rays.push((ray_dir, ray_pos.step(ray_dir)));
+            }
+        }
+
+        if step(&energized).is_break() {
+            break;
+        }
+    }
+
+    energized
+}
+
+fn solve(tiles: &Grid<char>, start: (Direction, Point)) -> usize {
+    energize(tiles, start, |_| ControlFlow::Continue(())).len()
+}
+
+fn part1(tiles: &Grid<char>) -> usize {
+    solve(tiles, (Direction::East, Point::new(0, 0)))
+}
+
+fn part2(tiles: &Grid<char>) -> usize {
+    let height = tiles.height() as i64;
+    let width = tiles.width() as i64;
+
+    let mut border = vec![];
+
+    for y in 0..height {
+        border.push((Direction::East, Point::new(0, y)));
+        border.push((Direction::West, Point::new(width - 1, y)));
+    }
+
+    for x in 0..width {
+        border.push((Direction::South, Point::new(x, 0)));
+        border.push((Direction::North, Point::new(x, height - 1)));
+    }
+
+    border.par_iter().map(|s| solve(tiles, *s)).max().unwrap()
+}
+
+fn canvas(tiles: &Grid<char>, energized: &AHashSet<Point>) -> Canvas {
+    let mut canvas = Canvas::new(tiles, |&t| match t {
+        '.' => Color::BLACK,
+        _ => Color::GRAY,
+    });
+
+    for &pos in energized {
+        let tile = if tiles[pos] == '.' {
+            Color::YELLOW
+        } else {
+            Color::ORANGE
+        };
+        canvas.fill(pos, tile);
+    }
+
+    canvas
+}
+
+fn render(tiles: &Grid<char>) -> Canvas {
+    let start = (Direction::East, Point::new(0, 0));
+    canvas(
+        tiles,
+        &energize(tiles, start, |_| ControlFlow::Continue(())),
+    )
+}
+
+fn animate(tiles: &Grid<char>, frames: &mut Frames) -> ControlFlow<()> {
+    let mut flow = ControlFlow::Continue(());
+    let start = (Direction::East, Point::new(0, 0));
+
+    energize(tiles, start, |energized| {
+        flow = frames(&canvas(tiles, energized));
+        flow
+    });
+
+    flow
+}
+
+/// Contraption of `size` x `size` tiles.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut contraption = Grid::filled(size, size, '.');
+
+    for pos in contraption.points().collect::<Vec<_>>() {
+        if rng.gen_bool(0.12) {
+            contraption[pos] = *['|', '-', '/', '\\'].choose(rng).unwrap();
+        }
+    }
+
+    generate::grid_text(&contraption)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+
+    type Input<'a> = Grid<char>;
+    type Config = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Grid<char>, _: &()) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Grid<char>, _: &()) -> usize {
+        part2(input)
+    }
+
+    fn render(input: &Grid<char>, _: &()) -> Option<Canvas> {
+        Some(render(input))
+    }
+
+    fn animate(input: &Grid<char>, _: &(), frames: &mut Frames) -> ControlFlow<()> {
+        animate(input, frames)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day16() {
+    let input = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 46);
+    assert_eq!(part2(&input), 51);
+}
@@ -0,0 +1,135 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::generate;
+use crate::graph::{self, Path};
+use crate::grid::{Direction, Grid, Point};
+use crate::parse::{Cursor, ParseError};
+use crate::render::{Canvas, Color};
+use crate::Solution;
+
+fn parse(input: &str) -> Result<Grid<i64>, ParseError> {
+    Cursor::new(input).grid("digit", |t| t.to_digit(10).map(i64::from))
+}
+
+/// Position and the direction the crucible moved in to get there.
+type State = (Point, Direction);
+
+fn neighbors((pos, last_dir): State, map: &Grid<i64>, min: i64, max: i64) -> Vec<(State, i64)> {
+    let mut neighbors = vec![];
+
+    for dir in [last_dir.turn_left(), last_dir.turn_right()] {
+        let step = dir.offset();
+
+        if map.contains(pos + step * min) {
+            let mut cost = (1..min).map(|d| map[pos + step * d]).sum::<i64>();
+
+            for dist in min..=max {
+                let Some(heat) = map.get(pos + step * dist) else {
+                    break;
+                };
+                cost += heat;
+
+                neighbors.push(((pos + step * dist, dir), cost));
+            }
+        }
+    }
+
+    neighbors
+}
+
+fn min_heat(map: &Grid<i64>, min: i64, max: i64) -> Option<Path<State>> {
+    let end = Point::new(map.width() as i64 - 1, map.height() as i64 - 1);
+    let starts = [Direction::East, Direction::South].map(|dir| (Point::new(0, 0), dir));
+
+    graph::dijkstra(
+        starts,
+        |&state| neighbors(state, map, min, max),
+        |&(pos, _)| pos == end,
+    )
+}
+
+fn part1(puzzle: &Grid<i64>) -> i64 {
+    min_heat(puzzle, 1, 3).unwrap().cost
+}
+
+fn part2(puzzle: &Grid<i64>) -> i64 {
+    min_heat(puzzle, 4, 10).unwrap().cost
+}
+
+/// Heat loss from black (1) to red (9) with the route of the ultra crucible.
+fn render(map: &Grid<i64>) -> Canvas {
+    let mut canvas = Canvas::new(map, |&heat| {
+        Color::BLACK.mix(Color::RED, (heat - 1) as f64 / 8.0)
+    });
+
+    if let Some(route) = min_heat(map, 4, 10) {
+        canvas.path(route.nodes.iter().map(|&(pos, _)| pos), Color::WHITE);
+    }
+
+    canvas
+}
+
+/// Map of `size` x `size` city blocks.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut blocks = Grid::filled(size, size, '1');
+
+    for pos in blocks.points().collect::<Vec<_>>() {
+        blocks[pos] = char::from(rng.gen_range(b'1'..=b'9'));
+    }
+
+    generate::grid_text(&blocks)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+
+    type Input<'a> = Grid<i64>;
+    type Config = ();
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Grid<i64>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Grid<i64>, _: &()) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Grid<i64>, _: &()) -> i64 {
+        part2(input)
+    }
+
+    fn render(input: &Grid<i64>, _: &()) -> Option<Canvas> {
+        Some(render(input))
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day17() {
+    let input = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 102);
+    assert_eq!(part2(&input), 94);
+}
@@ -0,0 +1,174 @@
+use std::fmt::Write;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::generate;
+use crate::geometry;
+use crate::grid::{Direction, Point};
+use crate::parse::{Cursor, ParseError};
+use crate::Solution;
+
+#[derive(Debug)]
+struct Instruction {
+    dir: Direction,
+    length: i64,
+}
+
+/// Dig instructions, read from the directions for part 1 and from the
+/// colour codes for part 2.
+pub struct Plan {
+    part1: Vec<Instruction>,
+    part2: Vec<Instruction>,
+}
+
+fn parse(input: &str) -> Result<Plan, ParseError> {
+    let instructions = Cursor::new(input).lines(|c| {
+        let dir = c.word()?;
+        let dir = match dir {
+            "R" => Direction::East,
+            "D" => Direction::South,
+            "L" => Direction::West,
+            "U" => Direction::North,
+            _ => return Err(c.error_at(dir, "R, D, L or U")),
+        };
+        let length = c.uint()?;
+
+        c.tag("(#")?;
+        let hex = c.token(|h| h.is_ascii_hexdigit(), "color")?;
+        if hex.len() != 6 {
+            return Err(c.error_at(hex, "six hex digits"));
+        }
+        c.tag(")")?;
+
+        let (hex_length, hex_dir) = hex.split_at(5);
+        let hex_dir = match hex_dir {
+            "0" => Direction::East,
+            "1" => Direction::South,
+            "2" => Direction::West,
+            "3" => Direction::North,
+            _ => return Err(c.error_at(hex_dir, "direction 0-3")),
+        };
+        let hex_length = i64::from_str_radix(hex_length, 16).unwrap();
+
+        Ok((
+            Instruction { dir, length },
+            Instruction {
+                dir: hex_dir,
+                length: hex_length,
+            },
+        ))
+    })?;
+
+    let (part1, part2) = instructions.into_iter().unzip();
+
+    Ok(Plan { part1, part2 })
+}
+
+fn solve(instructions: &[Instruction]) -> i64 {
+    let mut pos = Point::new(0, 0);
+    let mut border = vec![];
+
+    for instr in instructions {
+        pos += instr.dir.offset() * instr.length;
+        border.push(pos);
+    }
+
+    geometry::interior_points(&border) + geometry::boundary_points(&border)
+}
+
+/// Dig plan of about `size` instructions around a random tree. Both parts
+/// dig the same shape, stretched by different random coordinate maps.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let nodes = ((size as f64 / 2.0).sqrt().ceil() as usize).max(1);
+    let corners = generate::outline(&generate::spanning_tree(rng, nodes));
+
+    // strictly increasing maps from lattice to plan coordinates
+    let mut stretch = |max| {
+        let mut coords = vec![0];
+        for _ in 0..2 * nodes {
+            let last = coords[coords.len() - 1];
+            coords.push(last + rng.gen_range(1..=max));
+        }
+        coords
+    };
+    let (x1, y1, x2, y2) = (stretch(10), stretch(10), stretch(100_000), stretch(100_000));
+    let length = |xs: &[i64], ys: &[i64], a: Point, b: Point| {
+        (xs[a.x as usize] - xs[b.x as usize]).abs() + (ys[a.y as usize] - ys[b.y as usize]).abs()
+    };
+
+    // the outline starts at a corner, so runs of steps in one direction
+    // never wrap around
+    let mut input = String::new();
+    let mut from = corners[0];
+    for (i, &corner) in corners.iter().enumerate() {
+        let next = corners[(i + 1) % corners.len()];
+        let after = corners[(i + 2) % corners.len()];
+        if next - corner == after - next {
+            continue;
+        }
+
+        let (dir, code) = match next - corner {
+            offset if offset.x > 0 => ('R', 0),
+            offset if offset.y > 0 => ('D', 1),
+            offset if offset.x < 0 => ('L', 2),
+            _ => ('U', 3),
+        };
+        let (part1, part2) = (length(&x1, &y1, from, next), length(&x2, &y2, from, next));
+        writeln!(input, "{dir} {part1} (#{part2:05x}{code})").unwrap();
+        from = next;
+    }
+
+    input
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+
+    type Input<'a> = Plan;
+    type Config = ();
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Plan, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Plan, _: &()) -> i64 {
+        solve(&input.part1)
+    }
+
+    fn part2(input: &Plan, _: &()) -> i64 {
+        solve(&input.part2)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day18() {
+    let input = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+    let input = parse(input).unwrap();
+
+    assert_eq!(solve(&input.part1), 62);
+    assert_eq!(solve(&input.part2), 952408144115);
+}
@@ -0,0 +1,563 @@
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+
+use ahash::AHashMap;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::generate;
+use crate::parse::{Cursor, ParseError};
+use crate::validate::Assumption;
+use crate::Solution;
+
+/// Ratings of a machine part.
+#[derive(Debug)]
+pub struct Part {
+    pub x: i64,
+    pub m: i64,
+    pub a: i64,
+    pub s: i64,
+}
+
+impl Part {
+    fn resolve(&self, name: char) -> i64 {
+        match name {
+            'x' => self.x,
+            'm' => self.m,
+            'a' => self.a,
+            's' => self.s,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Rule<'a> {
+    operand: char,
+    operator: char,
+    value: i64,
+    label: &'a str,
+}
+
+#[derive(Debug)]
+struct Workflow<'a> {
+    rules: Vec<Rule<'a>>,
+    default: &'a str,
+}
+
+/// The workflows by name and the parts to sort.
+#[derive(Debug)]
+pub struct Puzzle<'a> {
+    parts: Vec<Part>,
+    workflows: AHashMap<&'a str, Workflow<'a>>,
+}
+
+fn parse(input: &str) -> Result<Puzzle<'_>, ParseError> {
+    let mut c = Cursor::new(input);
+
+    let workflows = c.block().lines(|c| {
+        let key = c.ident()?;
+        let mut rules = vec![];
+        let mut default = "";
+
+        c.tag("{")?;
+        c.separated(",", |c| {
+            let name = c.ident()?;
+
+            let operator = match () {
+                _ if c.try_tag("<") => '<',
+                _ if c.try_tag(">") => '>',
+                _ => {
+                    default = name;
+                    return Ok(());
+                }
+            };
+            let operand = match name {
+                "x" | "m" | "a" | "s" => name.chars().next().unwrap(),
+                _ => return Err(c.error_at(name, "x, m, a or s")),
+            };
+            let value = c.uint()?;
+            c.tag(":")?;
+            let label = c.ident()?;
+
+            rules.push(Rule {
+                operand,
+                operator,
+                value,
+                label,
+            });
+            Ok(())
+        })?;
+        c.tag("}")?;
+
+        Ok((key, Workflow { rules, default }))
+    })?;
+
+    let parts = c.block().lines(|c| {
+        let start = c.rest();
+        c.tag("{")?;
+        let values = c.key_values(",", "=", Cursor::uint)?;
+        c.tag("}")?;
+
+        match values[..] {
+            [("x", x), ("m", m), ("a", a), ("s", s)] => Ok(Part { x, m, a, s }),
+            _ => Err(c.error_at(start, "part `{x=..,m=..,a=..,s=..}`")),
+        }
+    })?;
+    c.end()?;
+
+    Ok(Puzzle {
+        parts,
+        workflows: workflows.into_iter().collect(),
+    })
+}
+
+impl Puzzle<'_> {
+    /// The parts to sort.
+    pub fn parts(&self) -> &[Part] {
+        &self.parts
+    }
+
+    /// Runs `part` through the workflows starting at `in`.
+    pub fn accepts(&self, part: &Part) -> bool {
+        let mut current = "in";
+        while current != "A" && current != "R" {
+            let workflow = &self.workflows[current];
+            let mut use_default = true;
+
+            for rule in workflow.rules.iter() {
+                let value = part.resolve(rule.operand);
+
+                match rule.operator {
+                    '<' => {
+                        if value < rule.value {
+                            current = rule.label;
+                            use_default = false;
+                            break;
+                        }
+                    }
+                    '>' => {
+                        if value > rule.value {
+                            current = rule.label;
+                            use_default = false;
+                            break;
+                        }
+                    }
+                    _ => unreachable!(),
+                }
+            }
+
+            if use_default {
+                current = workflow.default;
+            }
+        }
+
+        current == "A"
+    }
+
+    /// Number of parts with every rating in `ratings` that are accepted.
+    pub fn accepted_combinations(&self, ratings: RangeInclusive<i64>) -> i64 {
+        let ranges = AcceptedRanges {
+            x_range: ratings.clone(),
+            m_range: ratings.clone(),
+            a_range: ratings.clone(),
+            s_range: ratings,
+        };
+
+        solve(self, "in", &ranges)
+    }
+}
+
+fn part1(puzzle: &Puzzle) -> i64 {
+    puzzle
+        .parts()
+        .iter()
+        .filter(|part| puzzle.accepts(part))
+        .map(|part| part.x + part.m + part.a + part.s)
+        .sum()
+}
+
+#[derive(Debug, Clone)]
+struct AcceptedRanges {
+    x_range: RangeInclusive<i64>,
+    m_range: RangeInclusive<i64>,
+    a_range: RangeInclusive<i64>,
+    s_range: RangeInclusive<i64>,
+}
+
+impl AcceptedRanges {
+    fn range(&mut self, c: char) -> &mut RangeInclusive<i64> {
+        match c {
+            'x' => &mut self.x_range,
+            'm' => &mut self.m_range,
+            'a' => &mut self.a_range,
+            's' => &mut self.s_range,
+            _ => unreachable!(),
+        }
+    }
+
+    fn get(&self, c: char) -> &RangeInclusive<i64> {
+        match c {
+            'x' => &self.x_range,
+            'm' => &self.m_range,
+            'a' => &self.a_range,
+            's' => &self.s_range,
+            _ => unreachable!(),
+        }
+    }
+
+    fn replace_range(&mut self, c: char, range: &RangeInclusive<i64>) {
+        *self.range(c) = range.clone();
+    }
+}
+
+fn split_range(
+    at: i64,
+    range: &RangeInclusive<i64>,
+) -> Option<(RangeInclusive<i64>, RangeInclusive<i64>)> {
+    if range.contains(&at) {
+        Some((*range.start()..=at, at + 1..=*range.end()))
+    } else {
+        None
+    }
+}
+
+fn solve<'a>(puzzle: &'a Puzzle, current: &'a str, accepted: &AcceptedRanges) -> i64 {
+    // Luckily there are no overlapping ranges.
+    if current == "A" {
+        return [
+            &accepted.x_range,
+            &accepted.m_range,
+            &accepted.a_range,
+            &accepted.s_range,
+        ]
+        .iter()
+        .map(|r| r.end() - r.start() + 1)
+        .product();
+    } else if current == "R" {
+        return 0;
+    }
+
+    let workflow = &puzzle.workflows[current];
+    let mut accepted = accepted.clone();
+    let mut sum = 0;
+
+    for rule in workflow.rules.iter() {
+        match rule.operator {
+            '<' => {
+                if let Some((l, r)) = split_range(rule.value - 1, accepted.range(rule.operand)) {
+                    accepted.replace_range(rule.operand, &l);
+                    sum += solve(puzzle, rule.label, &accepted);
+                    accepted.replace_range(rule.operand, &r);
+                } else if rule.value >= *accepted.range(rule.operand).end() {
+                    sum += solve(puzzle, rule.label, &accepted);
+                }
+            }
+            '>' => {
+                if let Some((l, r)) = split_range(rule.value, accepted.range(rule.operand)) {
+                    accepted.replace_range(rule.operand, &r);
+                    sum += solve(puzzle, rule.label, &accepted);
+                    accepted.replace_range(rule.operand, &l);
+                } else if rule.value < *accepted.range(rule.operand).start() {
+                    sum += solve(puzzle, rule.label, &accepted);
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    sum += solve(puzzle, workflow.default, &accepted);
+
+    sum
+}
+
+fn part2(puzzle: &Puzzle) -> i64 {
+    puzzle.accepted_combinations(1..=4000)
+}
+
+/// Fails on the first rule that matches every part reaching it, `solve`
+/// would also pass those parts on to the following rules.
+fn check_splits(
+    puzzle: &Puzzle,
+    current: &str,
+    accepted: &AcceptedRanges,
+    depth: usize,
+) -> Result<(), String> {
+    // deeper paths only exist in cycles, which are reported separately
+    let Some(workflow) = puzzle.workflows.get(current) else {
+        return Ok(());
+    };
+    if depth > puzzle.workflows.len() {
+        return Ok(());
+    }
+
+    let mut accepted = accepted.clone();
+    for rule in &workflow.rules {
+        let range = accepted.get(rule.operand).clone();
+        let (matching, rest) = match rule.operator {
+            '<' => (
+                *range.start()..=(rule.value - 1).min(*range.end()),
+                rule.value.max(*range.start())..=*range.end(),
+            ),
+            _ => (
+                (rule.value + 1).max(*range.start())..=*range.end(),
+                *range.start()..=rule.value.min(*range.end()),
+            ),
+        };
+
+        if rest.is_empty() {
+            return Err(format!(
+                "{}{}{}:{} in {current} matches {}={}..{}",
+                rule.operand,
+                rule.operator,
+                rule.value,
+                rule.label,
+                rule.operand,
+                range.start(),
+                range.end()
+            ));
+        }
+        if !matching.is_empty() {
+            accepted.replace_range(rule.operand, &matching);
+            check_splits(puzzle, rule.label, &accepted, depth + 1)?;
+        }
+        accepted.replace_range(rule.operand, &rest);
+    }
+
+    check_splits(puzzle, workflow.default, &accepted, depth + 1)
+}
+
+/// A workflow on a cycle of workflows, if there is one.
+fn cycle<'a>(puzzle: &Puzzle<'a>) -> Option<&'a str> {
+    fn visit<'a>(
+        puzzle: &Puzzle<'a>,
+        current: &'a str,
+        path: &mut Vec<&'a str>,
+        done: &mut Vec<&'a str>,
+    ) -> Option<&'a str> {
+        if path.contains(&current) {
+            return Some(current);
+        }
+        let workflow = puzzle.workflows.get(current)?;
+        if done.contains(&current) {
+            return None;
+        }
+
+        path.push(current);
+        let labels = workflow.rules.iter().map(|r| r.label);
+        for next in labels.chain([workflow.default]) {
+            if let Some(node) = visit(puzzle, next, path, done) {
+                return Some(node);
+            }
+        }
+        path.pop();
+        done.push(current);
+
+        None
+    }
+
+    let (mut path, mut done) = (vec![], vec![]);
+    puzzle
+        .workflows
+        .keys()
+        .find_map(|name| visit(puzzle, name, &mut path, &mut done))
+}
+
+fn validate(puzzle: &Puzzle) -> Vec<Assumption> {
+    let labels = puzzle
+        .workflows
+        .values()
+        .flat_map(|w| w.rules.iter().map(|r| r.label).chain([w.default]));
+    let unknown = ["in"]
+        .into_iter()
+        .chain(labels)
+        .find(|&label| label != "A" && label != "R" && !puzzle.workflows.contains_key(label));
+
+    let ranges = AcceptedRanges {
+        x_range: 1..=4000,
+        m_range: 1..=4000,
+        a_range: 1..=4000,
+        s_range: 1..=4000,
+    };
+
+    vec![
+        Assumption::new(
+            "the workflow in and every workflow a rule sends parts to exist",
+            unknown.map_or(Ok(()), |label| Err(format!("{label} is missing"))),
+        ),
+        Assumption::new(
+            "no workflow sends parts back to itself through other workflows",
+            cycle(puzzle).map_or(Ok(()), |name| Err(format!("cycle through {name}"))),
+        ),
+        Assumption::new(
+            "no rule matches every part reaching it, so the ranges never overlap",
+            check_splits(puzzle, "in", &ranges, 0),
+        ),
+    ]
+}
+
+/// `size` workflows forming a tree below `in`, and `size` parts.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let mut names = vec!["in".to_string()];
+    names.extend(
+        generate::names(
+            rng,
+            size,
+            generate::name_len(size, 26).max(2),
+            "abcdefghijklmnopqrstuvwxyz",
+        )
+        .into_iter()
+        .filter(|name| name != "in")
+        .take(size - 1),
+    );
+
+    let mut workflows = vec![];
+    let mut queue = VecDeque::from([(
+        0,
+        AcceptedRanges {
+            x_range: 1..=4000,
+            m_range: 1..=4000,
+            a_range: 1..=4000,
+            s_range: 1..=4000,
+        },
+    )]);
+    let mut next = 1;
+
+    while let Some((workflow, mut ranges)) = queue.pop_front() {
+        let count = rng.gen_range(1..=3);
+        let mut rules = vec![];
+
+        for slot in 0..=count {
+            // every rule splits the parts reaching it, see `check_splits`
+            let splittable: Vec<_> = ['x', 'm', 'a', 's']
+                .into_iter()
+                .filter(|&c| ranges.get(c).end() > ranges.get(c).start())
+                .collect();
+            let fallback = slot == count || splittable.is_empty();
+            let mut matching = ranges.clone();
+
+            let rule = (!fallback).then(|| {
+                let category = *splittable.choose(rng).unwrap();
+                let (start, end) = (*ranges.get(category).start(), *ranges.get(category).end());
+                let (operator, value, matched, rest) = if rng.gen() {
+                    let value = rng.gen_range(start + 1..=end);
+                    ('<', value, start..=value - 1, value..=end)
+                } else {
+                    let value = rng.gen_range(start..end);
+                    ('>', value, value + 1..=end, start..=value)
+                };
+
+                matching.replace_range(category, &matched);
+                ranges.replace_range(category, &rest);
+                format!("{category}{operator}{value}")
+            });
+
+            // the tree must not end before every workflow is used
+            let last = queue.is_empty() && fallback;
+            let target = if next < names.len() && (last || rng.gen_bool(0.6)) {
+                queue.push_back((next, matching));
+                next += 1;
+                names[next - 1].clone()
+            } else if rng.gen() {
+                "A".to_string()
+            } else {
+                "R".to_string()
+            };
+
+            match rule {
+                Some(rule) => rules.push(format!("{rule}:{target}")),
+                None => {
+                    rules.push(target);
+                    break;
+                }
+            }
+        }
+
+        workflows.push(format!("{}{{{}}}", names[workflow], rules.join(",")));
+    }
+    workflows.shuffle(rng);
+
+    let mut input = workflows.join("\n") + "\n\n";
+    for _ in 0..size {
+        let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+        writeln!(input, "{{x={x},m={m},a={a},s={s}}}").unwrap();
+    }
+
+    input
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+
+    type Input<'a> = Puzzle<'a>;
+    type Config = ();
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Puzzle<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Puzzle<'_>, _: &()) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Puzzle<'_>, _: &()) -> i64 {
+        part2(input)
+    }
+
+    fn validate(input: &Puzzle<'_>, _: &()) -> Vec<Assumption> {
+        validate(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day19() {
+    let input = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 19114);
+    assert_eq!(part2(&input), 167409079868000);
+    assert!(validate(&input).iter().all(Assumption::holds));
+    let accepted: Vec<_> = input.parts().iter().map(|p| input.accepts(p)).collect();
+    assert_eq!(accepted, [true, false, true, false, true]);
+
+    let input = "\
+alpha{a>3500:A,a<501:A,R}
+in{alpha}
+
+";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part2(&input), 4000 * 4000 * 4000 * 1000);
+
+    let input = parse("in{x>2000:b,R}\nb{x>1000:A,in}\n\n").unwrap();
+    let holds: Vec<_> = validate(&input).iter().map(Assumption::holds).collect();
+    assert_eq!(holds, [true, false, false]);
+}
@@ -0,0 +1,449 @@
+use std::collections::VecDeque;
+#[cfg(feature = "graphviz")]
+use std::env;
+#[cfg(feature = "graphviz")]
+use std::io::Write;
+#[cfg(feature = "graphviz")]
+use std::path::PathBuf;
+#[cfg(feature = "graphviz")]
+use std::process::{Command, Stdio};
+
+use ahash::AHashMap;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::generate;
+use crate::math::lcm;
+use crate::parse::{Cursor, ParseError};
+#[cfg(feature = "graphviz")]
+use crate::render::Canvas;
+use crate::validate::Assumption;
+use crate::Solution;
+
+#[derive(Clone, Debug)]
+enum Kind<'a> {
+    Broadcast,
+    FlipFlop(bool),
+    Conjunction(AHashMap<&'a str, i64>),
+    Untyped,
+}
+
+/// Flip-flop, conjunction or broadcaster with the modules it sends pulses
+/// to.
+#[derive(Clone, Debug)]
+pub struct Module<'a> {
+    kind: Kind<'a>,
+    outputs: Vec<&'a str>,
+}
+
+fn parse(input: &str) -> Result<AHashMap<&str, Module<'_>>, ParseError> {
+    let mut modules = AHashMap::new();
+    let mut inputs = AHashMap::new();
+
+    Cursor::new(input).lines(|c| {
+        let mut kind = match () {
+            _ if c.try_tag("%") => Kind::FlipFlop(false),
+            _ if c.try_tag("&") => Kind::Conjunction(AHashMap::new()),
+            _ => Kind::Untyped,
+        };
+
+        let name = c.ident()?;
+        if name == "broadcaster" {
+            kind = Kind::Broadcast;
+        }
+
+        c.tag("->")?;
+        let outputs = c.separated(",", Cursor::ident)?;
+
+        for output in outputs.iter() {
+            inputs.entry(*output).or_insert(vec![]).push(name);
+        }
+
+        modules.insert(name, Module { kind, outputs });
+        Ok(())
+    })?;
+
+    // the first conjunction that no module sends pulses to
+    let unconnected = modules
+        .iter()
+        .filter(|(name, module)| {
+            matches!(module.kind, Kind::Conjunction(_)) && !inputs.contains_key(*name)
+        })
+        .map(|(name, _)| *name)
+        .min_by_key(|name| name.as_ptr());
+    if let Some(name) = unconnected {
+        return Err(ParseError::at(input, name, "conjunction with inputs"));
+    }
+
+    for (name, module) in modules.iter_mut() {
+        if let Kind::Conjunction(_) = module.kind {
+            module.kind = Kind::Conjunction(inputs[name].iter().map(|i| (*i, 0)).collect());
+        }
+    }
+
+    Ok(modules)
+}
+
+fn part1(modules: &AHashMap<&str, Module>) -> i64 {
+    let mut lows = 0;
+    let mut highs = 0;
+    let mut modules = modules.clone();
+    let mut inputs = VecDeque::new();
+
+    for _ in 0..1000 {
+        inputs.push_back(("broadcaster", "button", 0));
+        lows += 1;
+
+        while !inputs.is_empty() {
+            let mut new_inputs = VecDeque::new();
+            while let Some((dest, src, signal)) = inputs.pop_front() {
+                let Some(module) = modules.get_mut(dest) else {
+                    continue;
+                };
+
+                match &mut module.kind {
+                    Kind::Broadcast => {
+                        for output in module.outputs.iter() {
+                            new_inputs.push_back((*output, dest, signal));
+                        }
+                    }
+                    Kind::FlipFlop(state) => {
+                        if signal == 0 {
+                            for output in module.outputs.iter() {
+                                new_inputs.push_back((output, dest, if *state { 0 } else { 1 }));
+                            }
+                            module.kind = Kind::FlipFlop(!*state);
+                        }
+                    }
+                    Kind::Conjunction(inputs) => {
+                        inputs.insert(src, signal);
+
+                        if inputs.iter().all(|p| *p.1 == 1) {
+                            for output in module.outputs.iter() {
+                                new_inputs.push_back((output, dest, 0));
+                            }
+                        } else {
+                            for output in module.outputs.iter() {
+                                new_inputs.push_back((output, dest, 1));
+                            }
+                        }
+                    }
+                    Kind::Untyped => (),
+                }
+            }
+
+            inputs = new_inputs;
+
+            for (_, _, signal) in inputs.iter() {
+                if *signal == 0 {
+                    lows += 1;
+                } else {
+                    highs += 1;
+                }
+            }
+        }
+    }
+
+    lows * highs
+}
+
+/// Writes the module graph to `day20.png` in the temp dir with Graphviz.
+#[cfg(feature = "graphviz")]
+fn draw(modules: &AHashMap<&str, Module>) -> Result<PathBuf, String> {
+    let mut graph = String::from("digraph input {\n");
+    graph.push_str("  rx [style=filled, shape=star, color=orange];\n");
+    for (name, module) in modules.iter() {
+        let (shape, color, num) = match &module.kind {
+            Kind::Broadcast => ("doublecircle", "cyan", ""),
+            Kind::FlipFlop(_) => {
+                // flip-flops feeding a conjunction with several inputs are
+                // the 1 bits of a counting register
+                let counted = module.outputs.iter().any(|o| {
+                    matches!(modules.get(o), Some(Module { kind: Kind::Conjunction(inp), .. }) if inp.len() > 1)
+                });
+                ("diamond", "green", if counted { "(1)" } else { "(0)" })
+            }
+            Kind::Conjunction(_) => ("polygon", "red", ""),
+            Kind::Untyped => ("box", "gray", ""),
+        };
+        graph.push_str(&format!(
+            "  {name} [label=\"{name} {num}\", style=filled, shape={shape}, color={color}];\n"
+        ));
+        graph.push_str(&format!("  {name} -> {{{}}}\n", module.outputs.join(", ")));
+    }
+    graph.push_str("}\n");
+
+    let path = env::temp_dir().join("day20.png");
+    let mut dot = Command::new("dot")
+        .arg("-Tpng")
+        .arg("-o")
+        .arg(&path)
+        .stdin(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("cannot run dot: {err}"))?;
+
+    let written = dot
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(graph.as_bytes());
+    let status = dot.wait().map_err(|err| format!("dot failed: {err}"))?;
+    written.map_err(|err| format!("cannot write to dot: {err}"))?;
+
+    match status.success() {
+        true => Ok(path),
+        false => Err(format!("dot failed: {status}")),
+    }
+}
+
+/// Cycle lengths of the counting registers.
+fn cycles(modules: &AHashMap<&str, Module>) -> Vec<i64> {
+    // The part was first solved by looking at the graph. There are four
+    // conjunctions with more than one input, every input of which needs to be
+    // high. Each conjunction is connected to a sequence of flip-flops, which is
+    // essentially a counting register: walking the flip-flops from first to
+    // last and writing down a 1 if the flip-flop sends a signal to the
+    // conjunction and a 0 otherwise gives the number at which rx gets a low
+    // pulse and the register is cleared, so it loops (lcm). `registers` does
+    // the same walk on the input, `aoc --render` draws the graph.
+    registers(modules).unwrap_or_else(|err| panic!("{err}"))
+}
+
+fn part2(modules: &AHashMap<&str, Module>) -> i64 {
+    cycles(modules)
+        .iter()
+        .try_fold(1, |acc, n| lcm(acc, *n))
+        .expect("lcm of the cycle lengths overflows")
+}
+
+/// Checks that `rx` is fed by one conjunction whose inputs are inverters.
+fn rx_inputs(modules: &AHashMap<&str, Module>) -> Result<(), String> {
+    let feeding: Vec<_> = modules
+        .iter()
+        .filter(|(_, module)| module.outputs.contains(&"rx"))
+        .collect();
+
+    let [(name, module)] = feeding[..] else {
+        return Err(format!("{} modules send to rx", feeding.len()));
+    };
+    let Kind::Conjunction(inputs) = &module.kind else {
+        return Err(format!("{name} is no conjunction"));
+    };
+
+    for input in inputs.keys() {
+        match &modules[input].kind {
+            Kind::Conjunction(inputs) if inputs.len() == 1 => (),
+            _ => return Err(format!("{input} is no inverter")),
+        }
+    }
+
+    Ok(())
+}
+
+/// Cycle lengths of the counting registers: the broadcaster starts a chain
+/// of flip-flops for each, and every flip-flop that also sends to the
+/// register's conjunction is a 1 bit, starting with the lowest bit.
+fn registers(modules: &AHashMap<&str, Module>) -> Result<Vec<i64>, String> {
+    let broadcaster = modules
+        .get("broadcaster")
+        .ok_or("there is no broadcaster")?;
+
+    let flip_flop = |name: &str| {
+        modules
+            .get(name)
+            .is_some_and(|m| matches!(m.kind, Kind::FlipFlop(_)))
+    };
+
+    broadcaster
+        .outputs
+        .iter()
+        .map(|&first| {
+            let (mut value, mut bit, mut current) = (0, 0, Some(first));
+
+            while let Some(name) = current {
+                if !flip_flop(name) {
+                    return Err(format!("{name} in the register of {first} is no flip-flop"));
+                }
+                if bit == 63 {
+                    return Err(format!("the register of {first} does not end"));
+                }
+
+                let outputs = &modules[name].outputs;
+                let next: Vec<_> = outputs.iter().filter(|n| flip_flop(n)).collect();
+                if next.len() > 1 {
+                    return Err(format!("the register of {first} branches at {name}"));
+                }
+                if next.len() < outputs.len() {
+                    value |= 1 << bit;
+                }
+
+                bit += 1;
+                current = next.first().map(|n| **n);
+            }
+
+            Ok(value)
+        })
+        .collect()
+}
+
+fn validate(modules: &AHashMap<&str, Module>) -> Vec<Assumption> {
+    let registers = registers(modules);
+
+    vec![
+        Assumption::new(
+            "rx is fed by one conjunction of inverters",
+            rx_inputs(modules),
+        ),
+        Assumption::new(
+            "the broadcaster starts one chain of flip-flops per counting register",
+            registers.map(|_| ()),
+        ),
+    ]
+}
+
+/// `size` 12 bit counters like the real input: each counts button presses in
+/// a chain of flip-flops and resets through a conjunction once it reaches a
+/// random odd number, which then pulses `rx` through an inverter and a
+/// shared conjunction. At most five counters keep the answer within 60 bits.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    const BITS: usize = 12;
+    let size = size.clamp(1, 5);
+
+    let count = size * (BITS + 2) + 1;
+    let names: Vec<_> = generate::names(
+        rng,
+        count + 1,
+        generate::name_len(count + 1, 26).max(2),
+        "abcdefghijklmnopqrstuvwxyz",
+    )
+    .into_iter()
+    .filter(|name| name != "rx")
+    .take(count)
+    .collect();
+    let (hub, names) = names.split_last().unwrap();
+
+    let mut modules = vec![];
+    let mut starts = vec![];
+
+    for counter in names.chunks_exact(BITS + 2) {
+        let (flip_flops, rest) = counter.split_at(BITS);
+        let (reset, inverter) = (&rest[0], &rest[1]);
+        let cycle = rng.gen_range(1 << (BITS - 1)..1 << BITS) | 1;
+
+        for (bit, name) in flip_flops.iter().enumerate() {
+            let mut outputs = vec![];
+            if let Some(next) = flip_flops.get(bit + 1) {
+                outputs.push(next.as_str());
+            }
+            if cycle & (1 << bit) != 0 {
+                outputs.push(reset);
+            }
+            modules.push(format!("%{name} -> {}", outputs.join(", ")));
+        }
+
+        let mut outputs = vec![inverter.as_str()];
+        outputs.extend(
+            (0..BITS)
+                .filter(|bit| bit == &0 || cycle & (1 << bit) == 0)
+                .map(|bit| flip_flops[bit].as_str()),
+        );
+        modules.push(format!("&{reset} -> {}", outputs.join(", ")));
+        modules.push(format!("&{inverter} -> {hub}"));
+        starts.push(flip_flops[0].as_str());
+    }
+
+    modules.push(format!("&{hub} -> rx"));
+    modules.push(format!("broadcaster -> {}", starts.join(", ")));
+    modules.shuffle(rng);
+
+    modules.join("\n") + "\n"
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+
+    type Input<'a> = AHashMap<&'a str, Module<'a>>;
+    type Config = ();
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<AHashMap<&str, Module<'_>>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &AHashMap<&str, Module<'_>>, _: &()) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &AHashMap<&str, Module<'_>>, _: &()) -> i64 {
+        part2(input)
+    }
+
+    /// The module graph is not a grid, Graphviz draws it to the temp dir
+    /// instead.
+    #[cfg(feature = "graphviz")]
+    fn render(input: &AHashMap<&str, Module<'_>>, _: &()) -> Option<Canvas> {
+        match draw(input) {
+            Ok(path) => eprintln!("day 20: wrote the module graph to {}", path.display()),
+            Err(err) => eprintln!("day 20: cannot draw the module graph: {err}"),
+        }
+        None
+    }
+
+    fn validate(input: &AHashMap<&str, Module<'_>>, _: &()) -> Vec<Assumption> {
+        validate(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day20() {
+    let input1 = "\
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+    let input1 = parse(input1).unwrap();
+
+    let input2 = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+    let input2 = parse(input2).unwrap();
+
+    assert_eq!(part1(&input1), 32000000);
+    assert_eq!(part1(&input2), 11687500);
+
+    // a single 3 bit register counting to 0b101
+    let input3 = "\
+broadcaster -> a
+%a -> b, con
+%b -> c
+%c -> con
+&con -> a, b, inv
+&inv -> hub
+&hub -> rx";
+    let input3 = parse(input3).unwrap();
+
+    assert_eq!(registers(&input3), Ok(vec![0b101]));
+    let holds: Vec<_> = validate(&input3).iter().map(Assumption::holds).collect();
+    assert_eq!(holds, [true, true]);
+    assert_eq!(part2(&input3), 0b101);
+    assert!(!validate(&input2)[0].holds());
+}
+
+#[test]
+fn test_day20_errors() {
+    let err = parse("broadcaster -> a\n%a -> con\n&x -> a\n&con -> a\n").unwrap_err();
+    assert_eq!((err.line, err.column), (3, 2));
+    assert_eq!(err.found.as_deref(), Some("x"));
+}
@@ -0,0 +1,363 @@
+use std::ops::ControlFlow;
+
+use ahash::AHashSet;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::animate::Frames;
+use crate::generate;
+use crate::grid::{Grid, Point};
+use crate::parse::{Cursor, ParseError};
+use crate::render::{Canvas, Color};
+use crate::validate::Assumption;
+use crate::{param, Solution};
+
+fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Cursor::new(input).grid("`.`, `#` or `S`", |t| {
+        matches!(t, '.' | '#' | 'S').then_some(t)
+    })
+}
+
+fn center(map: &Grid<char>) -> Point {
+    Point::new(map.width() as i64 / 2, map.height() as i64 / 2)
+}
+
+pub struct Config {
+    pub part1_steps: usize,
+    pub part2_steps: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            part1_steps: 64,
+            part2_steps: 26501365,
+        }
+    }
+}
+
+/// Garden plots reachable in exactly `steps` steps on the finite map. `step`
+/// sees the frontier after every step and can stop the search early.
+fn reachable<F>(map: &Grid<char>, steps: usize, mut step: F) -> AHashSet<Point>
+where
+    F: FnMut(&AHashSet<Point>) -> ControlFlow<()>,
+{
+    let mut positions = AHashSet::new();
+
+    positions.insert(center(map));
+
+    for _ in 0..steps {
+        let mut new_positions = AHashSet::new();
+
+        for pos in positions {
+            for d in map.neighbors4(pos) {
+                if map[d] != '#' {
+                    new_positions.insert(d);
+                }
+            }
+        }
+
+        positions = new_positions;
+
+        if step(&positions).is_break() {
+            break;
+        }
+    }
+
+    positions
+}
+
+fn part1(map: &Grid<char>, steps: usize) -> usize {
+    reachable(map, steps, |_| ControlFlow::Continue(())).len()
+}
+
+fn part2(map: &Grid<char>, steps: usize) -> usize {
+    let mut positions = AHashSet::new();
+    let width = map.width();
+
+    positions.insert(center(map));
+
+    let mut prev = 0;
+    let mut prev_diff = 0;
+    let mut prev_diff_diff = 0;
+    let mut next_step = 0;
+
+    for step in 0..steps {
+        let mut new_positions = AHashSet::new();
+
+        for pos in positions.iter().copied() {
+            for d in pos.neighbors4() {
+                if *map.get_wrapped(d) != '#' {
+                    new_positions.insert(d);
+                }
+            }
+        }
+
+        if (step + 1) % width == steps % width {
+            let diff = new_positions.len() - prev;
+            let diff_diff = diff - prev_diff;
+            prev_diff = diff;
+            prev = new_positions.len();
+            if prev_diff_diff == diff_diff {
+                next_step = step + width;
+                positions = new_positions;
+                break;
+            }
+            prev_diff_diff = diff_diff;
+        }
+        positions = new_positions;
+    }
+
+    let mut plots = positions.len();
+
+    if next_step == 0 {
+        return plots;
+    }
+
+    // The number of plots grows quadratically after some steps.
+    // diff_diff is the change of diffs between width steps.
+    for _ in (next_step..steps).step_by(width) {
+        let diff = prev_diff + prev_diff_diff;
+        prev_diff = diff;
+        plots += diff;
+    }
+
+    plots
+}
+
+/// Checks that there are no rocks on the tiles selected by `on`.
+fn clear(map: &Grid<char>, on: impl Fn(Point) -> bool) -> Result<(), String> {
+    match map.iter().find(|&(pos, &t)| t == '#' && on(pos)) {
+        Some((pos, _)) => Err(format!("there is a rock at {},{}", pos.x, pos.y)),
+        None => Ok(()),
+    }
+}
+
+/// The quadratic extrapolation of part 2 needs the shape of the real input:
+/// the reachable plots spread through the clear middle lines and border, so
+/// that they grow by whole maps every `side` steps.
+fn validate(map: &Grid<char>) -> Vec<Assumption> {
+    let middle = center(map);
+    let (right, bottom) = (map.width() as i64 - 1, map.height() as i64 - 1);
+    let start = match map.position(|&t| t == 'S') {
+        Some(start) if start == center(map) => Ok(()),
+        Some(start) => Err(format!("S is at {},{}", start.x, start.y)),
+        None => Err("there is no S".to_string()),
+    };
+
+    vec![
+        Assumption::check("the map is square", map.width() == map.height()),
+        Assumption::check("the side of the map is odd", map.width() % 2 == 1),
+        Assumption::new("S is in the centre of the map", start),
+        Assumption::new(
+            "the row and column of S are clear",
+            clear(map, |p| p.x == middle.x || p.y == middle.y),
+        ),
+        Assumption::new(
+            "the border of the map is clear",
+            clear(map, |p| {
+                p.x == 0 || p.y == 0 || p.x == right || p.y == bottom
+            }),
+        ),
+    ]
+}
+
+fn canvas(map: &Grid<char>, positions: &AHashSet<Point>) -> Canvas {
+    let mut canvas = Canvas::new(map, |&t| match t {
+        '#' => Color::GRAY,
+        _ => Color::BLACK,
+    });
+
+    for &pos in positions {
+        canvas.fill(pos, Color::GREEN);
+    }
+    canvas.fill(center(map), Color::RED);
+
+    canvas
+}
+
+fn render(map: &Grid<char>, steps: usize) -> Canvas {
+    canvas(map, &reachable(map, steps, |_| ControlFlow::Continue(())))
+}
+
+fn animate(map: &Grid<char>, steps: usize, frames: &mut Frames) -> ControlFlow<()> {
+    let mut flow = ControlFlow::Continue(());
+
+    reachable(map, steps, |positions| {
+        flow = frames(&canvas(map, positions));
+        flow
+    });
+
+    flow
+}
+
+/// Garden of `size` x `size` plots, rounded up to an odd size, with the
+/// start in the middle of a clear row and column like the real input. Rocks
+/// never touch, so no plot is walled in.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let side = size.max(5) | 1;
+    let middle = (side / 2) as i64;
+    let mut garden = Grid::filled(side, side, '.');
+
+    for pos in garden.points().collect::<Vec<_>>() {
+        let clear = pos.x == middle
+            || pos.y == middle
+            || pos.x == 0
+            || pos.y == 0
+            || pos.x == side as i64 - 1
+            || pos.y == side as i64 - 1;
+        let touching = garden.neighbors8(pos).any(|p| garden[p] == '#');
+
+        if !clear && !touching && rng.gen_bool(0.15) {
+            garden[pos] = '#';
+        }
+    }
+
+    garden[Point::new(middle, middle)] = 'S';
+    generate::grid_text(&garden)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+
+    type Input<'a> = Grid<char>;
+    type Config = Config;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Grid<char>, config: &Config) -> usize {
+        part1(input, config.part1_steps)
+    }
+
+    fn part2(input: &Grid<char>, config: &Config) -> usize {
+        part2(input, config.part2_steps)
+    }
+
+    fn configure(config: &mut Config, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "part1_steps" => config.part1_steps = param(key, value)?,
+            "part2_steps" => config.part2_steps = param(key, value)?,
+            _ => return Err(format!("unknown parameter {key}")),
+        }
+        Ok(())
+    }
+
+    fn render(input: &Grid<char>, config: &Config) -> Option<Canvas> {
+        Some(render(input, config.part1_steps))
+    }
+
+    fn animate(input: &Grid<char>, config: &Config, frames: &mut Frames) -> ControlFlow<()> {
+        animate(input, config.part1_steps, frames)
+    }
+
+    fn validate(input: &Grid<char>, _: &Config) -> Vec<Assumption> {
+        validate(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day21() {
+    let input = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input, 6), 16);
+
+    assert_eq!(part2(&input, 6), 16);
+    assert_eq!(part2(&input, 10), 50);
+    assert_eq!(part2(&input, 50), 1594);
+    assert_eq!(part2(&input, 100), 6536);
+    assert_eq!(part2(&input, 500), 167004);
+    assert_eq!(part2(&input, 1000), 668697);
+    assert_eq!(part2(&input, 5000), 16733044);
+    // unlike the real input, the example has rocks in the row of S
+    let holds: Vec<_> = validate(&input).iter().map(Assumption::holds).collect();
+    assert_eq!(holds, [true, true, true, false, true]);
+}
+
+#[test]
+fn test_day21_reference() {
+    use std::collections::VecDeque;
+
+    use ahash::AHashMap;
+
+    use crate::property;
+
+    // breadth first search on the repeated map, a plot is reachable in
+    // exactly `steps` steps if it is reachable in fewer of the same parity
+    fn plots(map: &Grid<char>, steps: usize) -> usize {
+        let mut distances = AHashMap::from([(center(map), 0)]);
+        let mut queue = VecDeque::from([center(map)]);
+
+        while let Some(pos) = queue.pop_front() {
+            let distance = distances[&pos];
+            if distance == steps {
+                continue;
+            }
+
+            for next in pos.neighbors4() {
+                if *map.get_wrapped(next) != '#' && !distances.contains_key(&next) {
+                    distances.insert(next, distance + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        distances.values().filter(|&&d| d % 2 == steps % 2).count()
+    }
+
+    let garden = |rng: &mut StdRng| {
+        let size = rng.gen_range(5..=13);
+        let map = generate(rng, size);
+        (map, rng.gen_range(0..10 * size))
+    };
+    let shrink = |(text, steps): &(String, usize)| {
+        let maps = [property::remove_lines(text), property::remove_columns(text)].concat();
+        let smaller = [0, steps / 2, steps.saturating_sub(1)];
+
+        maps.into_iter()
+            .map(|map| (map, *steps))
+            .chain(
+                smaller
+                    .into_iter()
+                    .filter(|s| s < steps)
+                    .map(|s| (text.clone(), s)),
+            )
+            .collect()
+    };
+
+    let result = property::check(100, garden, shrink, |(text, steps)| {
+        let Ok(map) = parse(text) else {
+            return Ok(());
+        };
+        if !validate(&map).iter().all(Assumption::holds) {
+            return Ok(());
+        }
+
+        property::compare("part 2", part2(&map, *steps), plots(&map, *steps))
+    });
+
+    if let Err(failure) = result {
+        panic!("{failure}");
+    }
+}
@@ -0,0 +1,335 @@
+use std::ops::ControlFlow;
+
+use ahash::AHashSet;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rayon::prelude::*;
+
+use crate::animate::Frames;
+use crate::grid::{Grid, Point};
+use crate::parse::{Cursor, ParseError};
+use crate::render::{Canvas, Color};
+use crate::Solution;
+
+/// A brick from one corner to the other, both included.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Brick {
+    begin: (i64, i64, i64),
+    end: (i64, i64, i64),
+}
+
+impl Brick {
+    fn intersects(&self, other: &Brick) -> bool {
+        let self_max_x = self.begin.0.max(self.end.0);
+        let other_min_x = other.begin.0.min(other.end.0);
+
+        if self_max_x < other_min_x {
+            return false;
+        }
+
+        let self_min_x = self.begin.0.min(self.end.0);
+        let other_max_x = other.begin.0.max(other.end.0);
+
+        if self_min_x > other_max_x {
+            return false;
+        }
+
+        let self_max_y = self.begin.1.max(self.end.1);
+        let other_min_y = other.begin.1.min(other.end.1);
+
+        if self_max_y < other_min_y {
+            return false;
+        }
+
+        let self_min_y = self.begin.1.min(self.end.1);
+        let other_max_y = other.begin.1.max(other.end.1);
+
+        if self_min_y > other_max_y {
+            return false;
+        }
+
+        let self_max_z = self.begin.2.max(self.end.2);
+        let self_min_z = self.begin.2.min(self.end.2);
+        let other_max_z = other.begin.2.max(other.end.2);
+        let other_min_z = other.begin.2.min(other.end.2);
+
+        (self_max_z >= other_min_z) && (self_min_z <= other_max_z)
+    }
+
+    fn bottom(&self) -> i64 {
+        self.begin.2.min(self.end.2)
+    }
+}
+
+fn parse_point(c: &mut Cursor) -> Result<(i64, i64, i64), ParseError> {
+    let x = c.int()?;
+    c.tag(",")?;
+    let y = c.int()?;
+    c.tag(",")?;
+    let z = c.int()?;
+
+    Ok((x, y, z))
+}
+
+fn parse(input: &str) -> Result<Vec<Brick>, ParseError> {
+    Cursor::new(input).lines(|c| {
+        let begin = parse_point(c)?;
+        c.tag("~")?;
+        let end = parse_point(c)?;
+
+        Ok(Brick { begin, end })
+    })
+}
+
+fn does_fall(mut brick: Brick, skip: Vec<usize>, bricks: &[Brick]) -> Option<Brick> {
+    if brick.begin.2 == 1 || brick.end.2 == 1 {
+        return None;
+    }
+
+    brick.begin.2 -= 1;
+    brick.end.2 -= 1;
+
+    for (idx, other) in bricks.iter().enumerate() {
+        if !skip.contains(&idx) && brick.intersects(other) {
+            return None;
+        }
+    }
+
+    Some(brick)
+}
+
+/// Lets the bricks fall until they rest on the ground or on other bricks.
+/// `landed` sees the resting and the still falling bricks whenever a brick
+/// comes to rest and can stop the simulation early.
+fn settle<F>(bricks: &[Brick], mut landed: F) -> Vec<Brick>
+where
+    F: FnMut(&[Brick], &[Brick]) -> ControlFlow<()>,
+{
+    let mut falling_bricks = bricks.to_vec();
+    falling_bricks.sort_by_key(|r| std::cmp::Reverse(r.bottom()));
+
+    let mut fixed_bricks = Vec::with_capacity(falling_bricks.len());
+
+    while let Some(falling_brick) = falling_bricks.pop() {
+        if let Some(falling) = does_fall(falling_brick.clone(), vec![], &fixed_bricks) {
+            falling_bricks.push(falling);
+        } else {
+            fixed_bricks.push(falling_brick);
+
+            if landed(&fixed_bricks, &falling_bricks).is_break() {
+                break;
+            }
+        }
+    }
+
+    fixed_bricks
+}
+
+fn part1(bricks: &[Brick]) -> usize {
+    let fixed_bricks = settle(bricks, |_, _| ControlFlow::Continue(()));
+
+    (0..fixed_bricks.len())
+        .par_bridge()
+        .filter(|&idx| {
+            let mut causes_fall = false;
+            for other in 0..fixed_bricks.len() {
+                if other == idx {
+                    continue;
+                }
+
+                if does_fall(fixed_bricks[other].clone(), vec![other, idx], &fixed_bricks).is_some()
+                {
+                    causes_fall = true;
+                    break;
+                }
+            }
+
+            !causes_fall
+        })
+        .count()
+}
+
+fn part2(bricks: &[Brick]) -> usize {
+    let fixed_bricks = settle(bricks, |_, _| ControlFlow::Continue(()));
+
+    (0..fixed_bricks.len())
+        .par_bridge()
+        .map(|idx| {
+            let mut causes_fall = false;
+            for other in 0..fixed_bricks.len() {
+                if other == idx {
+                    continue;
+                }
+
+                if does_fall(fixed_bricks[other].clone(), vec![other, idx], &fixed_bricks).is_some()
+                {
+                    causes_fall = true;
+                    break;
+                }
+            }
+
+            if causes_fall {
+                let mut new_bricks = fixed_bricks.clone();
+
+                let mut falling = AHashSet::new();
+                let mut changed = true;
+                while changed {
+                    changed = false;
+                    for falling_idx in 0..fixed_bricks.len() {
+                        if let Some(brick) = does_fall(
+                            new_bricks[falling_idx].clone(),
+                            vec![idx, falling_idx],
+                            &new_bricks,
+                        ) {
+                            new_bricks[falling_idx] = brick;
+                            falling.insert(falling_idx);
+                            changed = true;
+                        }
+                    }
+                }
+                falling.len()
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+/// Side view of the bricks with x to the right and z upwards.
+fn side_view(bricks: &[Brick], fixed: &[Brick], falling: &[Brick]) -> Canvas {
+    let width = bricks
+        .iter()
+        .map(|b| b.begin.0.max(b.end.0))
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let height = bricks
+        .iter()
+        .map(|b| b.begin.2.max(b.end.2))
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let mut canvas = Canvas::new(&Grid::filled(width as usize, height as usize, ()), |_| {
+        Color::BLACK
+    });
+
+    for x in 0..width {
+        canvas.fill(Point::new(x, height - 1), Color::GRAY);
+    }
+
+    let fixed = fixed
+        .iter()
+        .enumerate()
+        .map(|(i, b)| (b, Color::BLUE.mix(Color::GREEN, (i % 8) as f64 / 7.0)));
+    let falling = falling.iter().map(|b| (b, Color::ORANGE));
+
+    for (brick, color) in fixed.chain(falling) {
+        for x in brick.begin.0.min(brick.end.0)..=brick.begin.0.max(brick.end.0) {
+            for z in brick.bottom()..=brick.begin.2.max(brick.end.2) {
+                canvas.fill(Point::new(x, height - 1 - z), color);
+            }
+        }
+    }
+
+    canvas
+}
+
+fn animate(bricks: &[Brick], frames: &mut Frames) -> ControlFlow<()> {
+    let mut flow = frames(&side_view(bricks, &[], bricks));
+
+    if flow.is_continue() {
+        settle(bricks, |fixed, falling| {
+            flow = frames(&side_view(bricks, fixed, falling));
+            flow
+        });
+    }
+
+    flow
+}
+
+/// `size` bricks above a 10 x 10 area, none of them overlapping.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut top = [[0; 10]; 10];
+    let mut bricks = vec![];
+
+    for _ in 0..size {
+        let len = rng.gen_range(0..4);
+        let (mut start, mut end) = ([0; 3], [0; 3]);
+        let axis = rng.gen_range(0..3);
+
+        for i in 0..2 {
+            let extent = if axis == i { len } else { 0 };
+            start[i] = rng.gen_range(0..10 - extent);
+            end[i] = start[i] + extent;
+        }
+
+        // above everything already in the columns it covers
+        let floor = (start[0]..=end[0])
+            .flat_map(|x| (start[1]..=end[1]).map(move |y| (x, y)))
+            .map(|(x, y)| top[x][y])
+            .max()
+            .unwrap();
+        start[2] = floor + 1 + rng.gen_range(0..3);
+        end[2] = start[2] + if axis == 2 { len } else { 0 };
+
+        for column in &mut top[start[0]..=end[0]] {
+            column[start[1]..=end[1]].fill(end[2]);
+        }
+
+        bricks.push(format!(
+            "{},{},{}~{},{},{}",
+            start[0], start[1], start[2], end[0], end[1], end[2]
+        ));
+    }
+    bricks.shuffle(rng);
+
+    bricks.join("\n") + "\n"
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+
+    type Input<'a> = Vec<Brick>;
+    type Config = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Brick>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Brick>, _: &()) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Brick>, _: &()) -> usize {
+        part2(input)
+    }
+
+    fn animate(input: &Vec<Brick>, _: &(), frames: &mut Frames) -> ControlFlow<()> {
+        animate(input, frames)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day22() {
+    let input = "\
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 5);
+    assert_eq!(part2(&input), 7);
+}
@@ -0,0 +1,307 @@
+use ahash::{AHashMap, AHashSet};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::generate;
+use crate::graph::{Graph, Path};
+use crate::grid::{Direction, Grid, Point};
+use crate::parse::{Cursor, ParseError};
+use crate::render::{Canvas, Color};
+use crate::validate::Assumption;
+use crate::Solution;
+
+fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Cursor::new(input).grid("`.`, `#` or a slope", |t| {
+        matches!(t, '.' | '#' | '>' | 'v' | '<' | '^').then_some(t)
+    })
+}
+
+type Neighbors = fn(&Grid<char>, Point) -> Vec<Point>;
+
+fn neighbors1(maze: &Grid<char>, pos: Point) -> Vec<Point> {
+    let mut posistions = vec![];
+
+    for dir in Direction::ALL {
+        let p = pos.step(dir);
+
+        match (maze.get(p), dir) {
+            (Some('.'), _) => posistions.push(p),
+            (Some('>'), Direction::East) => posistions.push(p),
+            (Some('v'), Direction::South) => posistions.push(p),
+            (Some('<'), Direction::West) => posistions.push(p),
+            (Some('^'), Direction::North) => posistions.push(p),
+            _ => (),
+        }
+    }
+
+    posistions
+}
+
+fn neighbors2(maze: &Grid<char>, pos: Point) -> Vec<Point> {
+    maze.neighbors4(pos).filter(|&p| maze[p] != '#').collect()
+}
+
+/// Tiles of the longest hike from the top left to the bottom right.
+fn longest_hike(maze: &Grid<char>, neighbors: Neighbors) -> Path<Point> {
+    let tiles: Vec<_> = maze.points().filter(|&p| maze[p] != '#').collect();
+    let ids: AHashMap<_, _> = tiles.iter().enumerate().map(|(i, &p)| (p, i)).collect();
+    let mut graph = Graph::new(tiles.len());
+
+    for (i, &pos) in tiles.iter().enumerate() {
+        for neighbor in neighbors(maze, pos) {
+            graph.add_edge(i, ids[&neighbor], 1);
+        }
+    }
+
+    let start = ids[&Point::new(1, 0)];
+    let end = ids[&Point::new(maze.width() as i64 - 2, maze.height() as i64 - 1)];
+
+    // only the junctions remain, few enough for an exhaustive search
+    let (contracted, kept) = graph.contract(|n| n == start || n == end);
+    let id = |node| kept.iter().position(|&n| n == node).unwrap();
+    let hike = contracted.longest_path(id(start), id(end)).unwrap();
+
+    let mut nodes = vec![start];
+    for pair in hike.nodes.windows(2) {
+        nodes.extend(corridor(&graph, &kept, kept[pair[0]], kept[pair[1]]));
+    }
+
+    Path {
+        nodes: nodes.into_iter().map(|n| tiles[n]).collect(),
+        cost: hike.cost,
+    }
+}
+
+/// Nodes after `from` of the longest corridor between the junctions `from`
+/// and `to`, the inverse of [`Graph::contract`].
+fn corridor(graph: &Graph, junctions: &[usize], from: usize, to: usize) -> Vec<usize> {
+    let mut longest: Vec<usize> = vec![];
+
+    for &(next, _) in graph.neighbors(from) {
+        let (mut prev, mut nodes) = (from, vec![next]);
+
+        while let Some(&curr) = nodes.last().filter(|n| !junctions.contains(n)) {
+            match graph.neighbors(curr).iter().find(|&&(n, _)| n != prev) {
+                Some(&(n, _)) => {
+                    prev = curr;
+                    nodes.push(n);
+                }
+                None => break,
+            }
+        }
+
+        if nodes.last() == Some(&to) && nodes.len() > longest.len() {
+            longest = nodes;
+        }
+    }
+
+    longest
+}
+
+fn part1(maze: &Grid<char>) -> i64 {
+    longest_hike(maze, neighbors1).cost
+}
+
+fn part2(maze: &Grid<char>) -> i64 {
+    longest_hike(maze, neighbors2).cost
+}
+
+fn validate(maze: &Grid<char>) -> Vec<Assumption> {
+    let start = Point::new(1, 0);
+    let end = Point::new(maze.width() as i64 - 2, maze.height() as i64 - 1);
+    let trail = |p| maze.get(p).is_some_and(|&t| t != '#');
+
+    // following the slopes downhill
+    let mut seen = AHashSet::from([start]);
+    let mut todo = vec![start];
+    while let Some(pos) = todo.pop() {
+        for next in neighbors1(maze, pos) {
+            if seen.insert(next) {
+                todo.push(next);
+            }
+        }
+    }
+
+    // the nodes that remain after contracting the corridors
+    let junctions = maze
+        .points()
+        .filter(|&p| maze[p] != '#' && neighbors2(maze, p).len() != 2)
+        .count();
+
+    vec![
+        Assumption::check(
+            "the hike starts at 1,0 and ends at the second to last tile of the bottom row",
+            trail(start) && trail(end),
+        ),
+        Assumption::check(
+            "the end can be reached without climbing a slope",
+            trail(start) && seen.contains(&end),
+        ),
+        Assumption::new(
+            "at most 64 junctions and dead ends for the exhaustive search",
+            if junctions <= 64 {
+                Ok(())
+            } else {
+                Err(format!("there are {junctions}"))
+            },
+        ),
+    ]
+}
+
+fn render(maze: &Grid<char>) -> Canvas {
+    let mut canvas = Canvas::new(maze, |&t| match t {
+        '#' => Color::DARK_GRAY,
+        '.' => Color::WHITE,
+        _ => Color::YELLOW,
+    });
+    canvas.path(longest_hike(maze, neighbors2).nodes, Color::RED);
+
+    canvas
+}
+
+/// Random gaps of at least 4 tiles between `count` coordinates from `first`
+/// to `last`.
+fn spread(rng: &mut StdRng, count: usize, first: usize, last: usize) -> Vec<usize> {
+    let mut gaps = vec![4; count - 1];
+    for _ in 0..last - first - 4 * (count - 1) {
+        gaps[rng.gen_range(0..count - 1)] += 1;
+    }
+
+    let mut coords = vec![first];
+    for gap in gaps {
+        coords.push(coords[coords.len() - 1] + gap);
+    }
+    coords
+}
+
+/// Map of about `size` x `size` tiles: a lattice of at most 6 x 6 junctions
+/// joined by straight trails, with slopes leaving every junction to the
+/// east and south.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let side = size.max(13);
+    let count = ((side - 5) / 4 + 1).min(6);
+    let xs = spread(rng, count, 1, side - 2);
+    let ys = spread(rng, count, 2, side - 3);
+    let junction = |x: usize, y: usize| Point::new(xs[x] as i64, ys[y] as i64);
+
+    // a spanning tree keeps every junction reachable, the top row and the
+    // right column leave a downhill way to the end
+    let tree = generate::spanning_tree(rng, count);
+    let mut trails = vec![];
+    for y in 0..count {
+        for x in 0..count {
+            let (tile_x, tile_y) = (2 * x as i64, 2 * y as i64);
+            if x + 1 < count
+                && (y == 0 || tree[Point::new(tile_x + 1, tile_y)] || rng.gen_bool(0.3))
+            {
+                trails.push((junction(x, y), junction(x + 1, y)));
+            }
+            if y + 1 < count
+                && (x + 1 == count || tree[Point::new(tile_x, tile_y + 1)] || rng.gen_bool(0.3))
+            {
+                trails.push((junction(x, y), junction(x, y + 1)));
+            }
+        }
+    }
+
+    let mut map = Grid::filled(side, side, '#');
+    let start = Point::new(1, 0);
+    let end = Point::new(side as i64 - 2, side as i64 - 1);
+    trails.push((start, junction(0, 0)));
+    trails.push((junction(count - 1, count - 1), end));
+
+    for &(from, to) in &trails {
+        let dir = if from.y == to.y {
+            Direction::East
+        } else {
+            Direction::South
+        };
+        let mut pos = from;
+        while pos != to {
+            map[pos] = '.';
+            pos = pos.step(dir);
+        }
+        map[to] = '.';
+
+        if from != start && to != end {
+            let slope = if dir == Direction::East { '>' } else { 'v' };
+            map[from.step(dir)] = slope;
+            map[to - dir.offset()] = slope;
+        }
+    }
+
+    generate::grid_text(&map)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+
+    type Input<'a> = Grid<char>;
+    type Config = ();
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Grid<char>, _: &()) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Grid<char>, _: &()) -> i64 {
+        part2(input)
+    }
+
+    fn render(input: &Grid<char>, _: &()) -> Option<Canvas> {
+        Some(render(input))
+    }
+
+    fn validate(input: &Grid<char>, _: &()) -> Vec<Assumption> {
+        validate(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day23() {
+    let input = "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 94);
+    assert_eq!(part2(&input), 154);
+    assert_eq!(longest_hike(&input, neighbors2).nodes.len(), 155);
+    assert!(validate(&input).iter().all(Assumption::holds));
+
+    let uphill = parse("#.###\n#.<.#\n###.#\n").unwrap();
+    let holds: Vec<_> = validate(&uphill).iter().map(Assumption::holds).collect();
+    assert_eq!(holds, [true, false, true]);
+}
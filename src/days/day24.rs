@@ -0,0 +1,343 @@
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+
+use ahash::AHashSet;
+use rand::rngs::StdRng;
+use rand::Rng;
+#[cfg(feature = "z3")]
+use z3::ast::{Ast, Int, Real};
+#[cfg(feature = "z3")]
+use z3::{Context, Solver};
+
+use crate::parse::{Cursor, ParseError};
+use crate::{param, Solution};
+
+/// Position and velocity of a hailstone.
+#[derive(Debug)]
+pub struct Hail {
+    x: f64,
+    y: f64,
+    z: f64,
+    vx: f64,
+    vy: f64,
+    vz: f64,
+}
+
+fn parse_triple(c: &mut Cursor) -> Result<(f64, f64, f64), ParseError> {
+    let x = c.int()?;
+    c.tag(",")?;
+    let y = c.int()?;
+    c.tag(",")?;
+    let z = c.int()?;
+
+    Ok((x, y, z))
+}
+
+fn parse(input: &str) -> Result<Vec<Hail>, ParseError> {
+    Cursor::new(input).lines(|c| {
+        let (x, y, z) = parse_triple(c)?;
+        c.tag("@")?;
+        let (vx, vy, vz) = parse_triple(c)?;
+
+        Ok(Hail {
+            x,
+            y,
+            z,
+            vx,
+            vy,
+            vz,
+        })
+    })
+}
+
+pub struct Config {
+    /// Test area of part 1, both for x and y.
+    pub bounds: RangeInclusive<f64>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            bounds: 200000000000000.0..=400000000000000.0,
+        }
+    }
+}
+
+type Point = (f64, f64);
+
+// https://en.wikipedia.org/wiki/Line%E2%80%93line_intersection#Given_two_points_on_each_line_segment
+fn intersection(left: &Hail, right: &Hail) -> Option<Point> {
+    let a = left.vy / left.vx;
+    let c = left.y - (left.x * a);
+
+    let b = right.vy / right.vx;
+    let d = right.y - (right.x * b);
+
+    if (a - b).abs() < f64::EPSILON {
+        return None;
+    }
+
+    let x = (d - c) / (a - b);
+    let y = a * x + c;
+
+    Some((x, y))
+}
+
+fn part1(puzzle: &[Hail], bounds: RangeInclusive<f64>) -> i64 {
+    let mut crossings = 0;
+
+    for (left, lhail) in puzzle.iter().enumerate() {
+        for (right, rhail) in puzzle.iter().enumerate() {
+            if left < right {
+                let Some(inter) = intersection(rhail, lhail) else {
+                    continue;
+                };
+
+                if (inter.0 - lhail.x > 0.0) == (lhail.vx > 0.0)
+                    && (inter.1 - lhail.y > 0.0) == (lhail.vy > 0.0)
+                    && (inter.0 - rhail.x > 0.0) == (rhail.vx > 0.0)
+                    && (inter.1 - rhail.y > 0.0) == (rhail.vy > 0.0)
+                    && bounds.contains(&inter.0)
+                    && bounds.contains(&inter.1)
+                {
+                    crossings += 1;
+                }
+            }
+        }
+    }
+
+    crossings
+}
+
+#[cfg(feature = "z3")]
+fn part2(puzzle: &[Hail]) -> i64 {
+    let cfg = z3::Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+    let x = Real::new_const(&ctx, "X");
+    let y = Real::new_const(&ctx, "Y");
+    let z = Real::new_const(&ctx, "Z");
+
+    let vx = Real::new_const(&ctx, "VX");
+    let vy = Real::new_const(&ctx, "VY");
+    let vz = Real::new_const(&ctx, "VZ");
+
+    // z3 is really cool :)
+    for stone in puzzle.iter() {
+        // real is much faster than int
+        let x_other = Int::from_i64(&ctx, stone.x as i64).to_real();
+        let y_other = Int::from_i64(&ctx, stone.y as i64).to_real();
+        let z_other = Int::from_i64(&ctx, stone.z as i64).to_real();
+        let tn = Real::fresh_const(&ctx, "T");
+
+        let vx_other = Int::from_i64(&ctx, stone.vx as i64).to_real();
+        let vy_other = Int::from_i64(&ctx, stone.vy as i64).to_real();
+        let vz_other = Int::from_i64(&ctx, stone.vz as i64).to_real();
+
+        let stone_move_x = &x + &vx * &tn;
+        let stone_move_y = &y + &vy * &tn;
+        let stone_move_z = &z + &vz * &tn;
+
+        let other_move_x = &x_other + &vx_other * &tn;
+        let other_move_y = &y_other + &vy_other * &tn;
+        let other_move_z = &z_other + &vz_other * &tn;
+
+        solver.assert(&stone_move_x._eq(&other_move_x));
+        solver.assert(&stone_move_y._eq(&other_move_y));
+        solver.assert(&stone_move_z._eq(&other_move_z));
+    }
+
+    solver.check();
+    let model = solver.get_model().unwrap();
+
+    if let (Some(x), Some(y), Some(z)) = (
+        model.get_const_interp(&x).and_then(|x| x.as_real()),
+        model.get_const_interp(&y).and_then(|y| y.as_real()),
+        model.get_const_interp(&z).and_then(|z| z.as_real()),
+    ) {
+        assert!(x.1 == 1 && y.1 == 1 && z.1 == 1);
+        x.0 + y.0 + z.0
+    } else {
+        unreachable!()
+    }
+}
+
+/// Largest x and y speed of the rock that [`part2`] tries without z3.
+#[cfg(not(feature = "z3"))]
+const MAX_SPEED: i128 = 1000;
+
+/// Position of a rock thrown with `speed` in x and y that hits every
+/// hailstone at an integer time, in exact integer arithmetic. In the frame of
+/// the rock the hailstones move by their velocity minus `speed`, and all of
+/// them pass through the starting point of the rock.
+#[cfg(not(feature = "z3"))]
+fn throw(hail: &[[i128; 6]], speed: (i128, i128)) -> Option<[i128; 3]> {
+    let cross = |a: (i128, i128), b: (i128, i128)| a.0 * b.1 - a.1 * b.0;
+    let relative = |h: &[i128; 6]| (h[3] - speed.0, h[4] - speed.1);
+
+    // any two hailstones that do not move in parallel in the frame of the
+    // rock, a hailstone with the x and y speed of the rock does not move at
+    // all
+    let (first, other, det) = hail
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| hail[i + 1..].iter().map(move |b| (a, b)))
+        .map(|(a, b)| (a, b, cross(relative(a), relative(b))))
+        .find(|&(_, _, det)| det != 0)?;
+
+    // first + t * relative(first) = other + s * relative(other)
+    let offset = (other[0] - first[0], other[1] - first[1]);
+    let t = cross(offset, relative(other));
+    if t % det != 0 {
+        return None;
+    }
+    let t = t / det;
+    let (x, y) = (
+        first[0] + t * relative(first).0,
+        first[1] + t * relative(first).1,
+    );
+
+    // the time of `other` gives the z speed
+    let s = match relative(other) {
+        (0, dy) => (y - other[1]) / dy,
+        (dx, _) => (x - other[0]) / dx,
+    };
+    if s == t {
+        return None;
+    }
+    let vz = (first[2] + t * first[5] - other[2] - s * other[5]) / (t - s);
+    let z = first[2] + t * (first[5] - vz);
+
+    let rock = [x, y, z, speed.0, speed.1, vz];
+    let hits = |h: &[i128; 6]| {
+        let times: Vec<_> = (0..3)
+            .filter(|&i| rock[i + 3] != h[i + 3])
+            .map(|i| (h[i] - rock[i], rock[i + 3] - h[i + 3]))
+            .collect();
+
+        (0..3).all(|i| rock[i + 3] != h[i + 3] || rock[i] == h[i])
+            && times
+                .iter()
+                .all(|&(d, v)| d % v == 0 && d / v >= 0 && d / v == times[0].0 / times[0].1)
+    };
+
+    hail.iter().all(hits).then_some([x, y, z])
+}
+
+/// Without z3 the x and y speed of the rock are searched: the rock hits every
+/// hailstone, so for the right speed all hailstones meet in a single point in
+/// the frame of the rock.
+#[cfg(not(feature = "z3"))]
+fn part2(puzzle: &[Hail]) -> i64 {
+    let hail: Vec<_> = puzzle
+        .iter()
+        .map(|h| [h.x, h.y, h.z, h.vx, h.vy, h.vz].map(|n| n as i128))
+        .collect();
+
+    (-MAX_SPEED..=MAX_SPEED)
+        .flat_map(|vx| (-MAX_SPEED..=MAX_SPEED).map(move |vy| (vx, vy)))
+        .find_map(|speed| throw(&hail, speed))
+        .map(|rock| rock.iter().sum::<i128>() as i64)
+        .expect("no rock hits every hailstone")
+}
+
+/// `size` hailstones that a single thrown rock hits at distinct times.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let rock = [(); 3].map(|_| rng.gen_range(200_000_000_000_000..400_000_000_000_000i64));
+    let throw = [(); 3].map(|_| rng.gen_range(-200..=200i64));
+    let mut times = AHashSet::new();
+    let mut input = String::new();
+
+    while times.len() < size.max(3) {
+        let time = rng.gen_range(1_000_000_000..1_000_000_000_000i64);
+        if !times.insert(time) {
+            continue;
+        }
+
+        // the hailstone is where the rock is at `time`
+        let velocity = [(); 3].map(|_| rng.gen_range(-300..=300i64));
+        let position: Vec<_> = (0..3)
+            .map(|i| rock[i] + (throw[i] - velocity[i]) * time)
+            .collect();
+
+        writeln!(
+            input,
+            "{}, {}, {} @ {}, {}, {}",
+            position[0], position[1], position[2], velocity[0], velocity[1], velocity[2]
+        )
+        .unwrap();
+    }
+
+    input
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+
+    type Input<'a> = Vec<Hail>;
+    type Config = Config;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Hail>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Hail>, config: &Config) -> i64 {
+        part1(input, config.bounds.clone())
+    }
+
+    fn part2(input: &Vec<Hail>, _: &Config) -> i64 {
+        part2(input)
+    }
+
+    /// `bounds` is given as a range like `7..=27`.
+    fn configure(config: &mut Config, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "bounds" => {
+                let (min, max) = value
+                    .split_once("..=")
+                    .ok_or_else(|| format!("invalid value of {key}: {value}"))?;
+                config.bounds = param(key, min)?..=param(key, max)?;
+            }
+            _ => return Err(format!("unknown parameter {key}")),
+        }
+        Ok(())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day24() {
+    let input = "\
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input, 7.0..=27.0), 2);
+    assert_eq!(part2(&input), 47);
+}
+
+#[test]
+fn test_day24_same_speed() {
+    // the first hailstone has the x and y speed of the rock of the example
+    // and is hit at time 3
+    let input = "\
+24, 13, 16 @ -3,  1,  0
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part2(&input), 47);
+}
@@ -0,0 +1,338 @@
+use std::collections::BTreeMap;
+
+use ahash::AHashMap;
+use rand::prelude::*;
+
+use crate::cancel;
+use crate::generate;
+use crate::graph::Graph;
+use crate::parse::{Cursor, ParseError};
+use crate::validate::Assumption;
+use crate::{param, Solution};
+
+fn parse(input: &str) -> Result<Graph, ParseError> {
+    let mut graph = Graph::new(0);
+    let mut ids = AHashMap::new();
+    let mut id = |graph: &mut Graph, name| *ids.entry(name).or_insert_with(|| graph.add_node());
+
+    Cursor::new(input).lines(|c| {
+        let from = c.ident()?;
+        c.tag(":")?;
+        let from = id(&mut graph, from);
+
+        for to in c.many(Cursor::ident)? {
+            let to = id(&mut graph, to);
+            graph.add_undirected_edge(from, to, 1);
+        }
+
+        Ok(())
+    })?;
+
+    Ok(graph)
+}
+
+fn find(parents: &mut [usize], node: usize) -> usize {
+    let mut root = node;
+    while parents[root] != root {
+        root = parents[root];
+    }
+
+    let mut node = node;
+    while parents[node] != root {
+        (node, parents[node]) = (parents[node], root);
+    }
+
+    root
+}
+
+pub struct Config {
+    /// Seed of the random edge orders.
+    pub seed: u64,
+    /// Karger trials after which part 1 uses the deterministic minimum cut.
+    pub max_trials: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            seed: 0,
+            max_trials: 10_000,
+        }
+    }
+}
+
+/// Product of the group sizes and the number of trials it took.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Cut {
+    product: usize,
+    trials: usize,
+}
+
+fn karger(graph: &Graph, config: &Config) -> Result<Cut, String> {
+    let mut edges: Vec<_> = graph.edges().filter(|(a, b, _)| a < b).collect();
+    let mut rng = generate::rng(config.seed);
+
+    // Run https://en.wikipedia.org/wiki/Karger%27s_algorithm until the last
+    // two remaining vertices are connected by 3 edges. Contracting the edges
+    // in a random order picks every next edge uniformly.
+    for trials in 1..=config.max_trials {
+        cancel::check();
+        edges.shuffle(&mut rng);
+
+        let mut parents: Vec<_> = (0..graph.len()).collect();
+        let mut vertices = graph.len();
+
+        for &(a, b, _) in &edges {
+            if vertices == 2 {
+                break;
+            }
+
+            let (a, b) = (find(&mut parents, a), find(&mut parents, b));
+            if a != b {
+                parents[a] = b;
+                vertices -= 1;
+            }
+        }
+
+        let cut = edges
+            .iter()
+            .filter(|&&(a, b, _)| find(&mut parents, a) != find(&mut parents, b))
+            .count();
+
+        if cut == 3 {
+            let root = find(&mut parents, 0);
+            let size = (0..graph.len())
+                .filter(|&n| find(&mut parents, n) == root)
+                .count();
+
+            return Ok(Cut {
+                product: size * (graph.len() - size),
+                trials,
+            });
+        }
+    }
+
+    Err(format!(
+        "no cut of 3 wires after {} trials with seed {}",
+        config.max_trials, config.seed
+    ))
+}
+
+/// Product of the group sizes of the deterministic minimum cut, which has to
+/// be a cut of 3 wires.
+fn min_cut(graph: &Graph) -> Result<usize, String> {
+    match graph.min_cut() {
+        Some((3, side)) => Ok(side.len() * (graph.len() - side.len())),
+        Some((cut, _)) => Err(format!("the minimum cut has {cut} wires")),
+        None => Err("there are less than two components".to_string()),
+    }
+}
+
+/// Karger's product of the group sizes, or the one of the slower but
+/// deterministic minimum cut if Karger gives up after `max_trials`. Panics
+/// with both reasons if there is no cut of 3 wires.
+fn part1(graph: &Graph, config: &Config) -> usize {
+    match karger(graph, config) {
+        Ok(cut) => cut.product,
+        Err(err) => min_cut(graph).unwrap_or_else(|cut| panic!("{err}, {cut}")),
+    }
+}
+
+fn validate(graph: &Graph) -> Vec<Assumption> {
+    vec![Assumption::new(
+        "the minimum cut has 3 wires",
+        min_cut(graph).map(|_| ()),
+    )]
+}
+
+/// Trials needed with the seed of `config` and their spread over it and the
+/// next seeds.
+fn stats(graph: &Graph, config: &Config) -> Vec<(&'static str, String)> {
+    const SEEDS: u64 = 20;
+
+    let trials: Vec<_> = (0..SEEDS)
+        .map(|i| {
+            let seed = config.seed.wrapping_add(i);
+            karger(graph, &Config { seed, ..*config }).map(|cut| cut.trials)
+        })
+        .collect();
+
+    let mut stats = vec![(
+        "trials",
+        match &trials[0] {
+            Ok(trials) => trials.to_string(),
+            Err(err) => format!("{err}, part 1 used the minimum cut"),
+        },
+    )];
+
+    if let Ok(mut trials) = trials.into_iter().collect::<Result<Vec<_>, _>>() {
+        trials.sort_unstable();
+        stats.push((
+            "trials over 20 seeds",
+            format!(
+                "min {}, median {}, max {}",
+                trials[0],
+                trials[trials.len() / 2],
+                trials[trials.len() - 1]
+            ),
+        ));
+    }
+
+    stats
+}
+
+/// `size` components in two densely wired halves joined by exactly three
+/// wires.
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(10);
+    let len = generate::name_len(size, 26).max(3);
+    let names = generate::names(rng, size, len, "abcdefghijklmnopqrstuvwxyz");
+    let (left, right) = names.split_at(rng.gen_range(size / 3..=size - size / 3));
+    let mut wires = vec![];
+
+    // a random tree plus four extra wires per component keeps every cut
+    // within a half well above three wires
+    for half in [left, right] {
+        for (i, name) in half.iter().enumerate().skip(1) {
+            wires.push((name, &half[rng.gen_range(0..i)]));
+        }
+        for name in half {
+            for other in half.choose_multiple(rng, 4) {
+                if name != other
+                    && !wires.contains(&(name, other))
+                    && !wires.contains(&(other, name))
+                {
+                    wires.push((name, other));
+                }
+            }
+        }
+    }
+    for _ in 0..3 {
+        loop {
+            let wire = (left.choose(rng).unwrap(), right.choose(rng).unwrap());
+            if !wires.contains(&wire) {
+                wires.push(wire);
+                break;
+            }
+        }
+    }
+
+    let mut connections: BTreeMap<&String, Vec<&str>> = BTreeMap::new();
+    for (from, to) in wires {
+        connections.entry(from).or_default().push(to);
+    }
+    let mut lines: Vec<_> = connections
+        .into_iter()
+        .map(|(from, to)| format!("{from}: {}", to.join(" ")))
+        .collect();
+    lines.shuffle(rng);
+
+    lines.join("\n") + "\n"
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+
+    type Input<'a> = Graph;
+    type Config = Config;
+    type Answer1 = usize;
+    type Answer2 = ();
+
+    fn parse(input: &str) -> Result<Graph, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Graph, config: &Config) -> usize {
+        part1(input, config)
+    }
+
+    fn part2(_: &Graph, _: &Config) {}
+
+    fn validate(input: &Graph, _: &Config) -> Vec<Assumption> {
+        validate(input)
+    }
+
+    fn seeded(seed: u64) -> Config {
+        Config {
+            seed,
+            ..Config::default()
+        }
+    }
+
+    fn configure(config: &mut Config, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "seed" => config.seed = param(key, value)?,
+            "max_trials" => config.max_trials = param(key, value)?,
+            _ => return Err(format!("unknown parameter {key}")),
+        }
+        Ok(())
+    }
+
+    fn stats(input: &Graph, config: &Config) -> Vec<(&'static str, String)> {
+        stats(input, config)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[test]
+fn test_day25() {
+    let input = "\
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input, &Config::default()), 54);
+
+    // the same seed takes the same trials
+    let config = Config::default();
+    let cut = karger(&input, &config).unwrap();
+    assert_eq!(karger(&input, &config), Ok(cut));
+    assert!(cut.trials <= config.max_trials);
+
+    let config = Config {
+        seed: 1,
+        max_trials: 0,
+    };
+    assert_eq!(
+        karger(&input, &config),
+        Err("no cut of 3 wires after 0 trials with seed 1".to_string())
+    );
+    assert_eq!(
+        stats(&input, &config)[0].1,
+        "no cut of 3 wires after 0 trials with seed 1, part 1 used the minimum cut"
+    );
+    assert_eq!(part1(&input, &config), 54);
+    assert!(validate(&input)[0].holds());
+
+    let config = Config {
+        seed: 1,
+        max_trials: 10,
+    };
+    let result = crate::cancel::isolate(None, || part1(&parse("a: b\n").unwrap(), &config));
+    assert_eq!(
+        result,
+        Err(crate::cancel::Abort::Panicked(
+            "no cut of 3 wires after 10 trials with seed 1, the minimum cut has 1 wires"
+                .to_string()
+        ))
+    );
+
+    let (cut, side) = input.min_cut().unwrap();
+    assert_eq!((cut, side.len() * (input.len() - side.len())), (3, 54));
+}
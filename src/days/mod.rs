@@ -0,0 +1,86 @@
+use crate::Day;
+
+/// Trebuchet?!: the first and last digit of every line.
+pub mod day01;
+/// Cube Conundrum: games possible with a bag of cubes.
+pub mod day02;
+/// Gear Ratios: part numbers next to symbols.
+pub mod day03;
+/// Scratchcards: winning cards that win copies of the next cards.
+pub mod day04;
+/// If You Give A Seed A Fertilizer: maps applied to whole ranges of seeds.
+pub mod day05;
+/// Wait For It: the button times that win a boat race, solved as a quadratic
+/// inequality.
+pub mod day06;
+/// Camel Cards: ranking poker-like hands, with jokers in part 2.
+pub mod day07;
+/// Haunted Wasteland: walking a network of nodes, the ghosts meet at the lcm of
+/// their cycles.
+pub mod day08;
+/// Mirage Maintenance: extrapolating sequences by their differences.
+pub mod day09;
+/// Pipe Maze: the loop through the animal and the tiles it encloses.
+pub mod day10;
+/// Cosmic Expansion: distances between galaxies in an expanding universe.
+pub mod day11;
+/// Hot Springs: counting the arrangements of damaged springs.
+pub mod day12;
+/// Point of Incidence: reflection lines of patterns, with a smudge in part 2.
+pub mod day13;
+/// Parabolic Reflector Dish: tilting rocks, with cycle detection for a billion
+/// spin cycles.
+pub mod day14;
+/// Lens Library: the HASH algorithm and the boxes of lenses.
+pub mod day15;
+/// The Floor Will Be Lava: beams of light through mirrors and splitters.
+pub mod day16;
+/// Clumsy Crucible: shortest paths with limits on straight moves.
+pub mod day17;
+/// Lavaduct Lagoon: area of a dug polygon with the shoelace formula.
+pub mod day18;
+/// Aplenty: workflows that accept or reject parts, and ranges of ratings.
+pub mod day19;
+/// Pulse Propagation: modules passing pulses, with counting registers feeding
+/// rx.
+pub mod day20;
+/// Step Counter: plots reachable in a number of steps on an infinite map.
+pub mod day21;
+/// Sand Slabs: falling bricks and the ones that can be disintegrated.
+pub mod day22;
+/// A Long Walk: the longest hike through a maze of trails.
+pub mod day23;
+/// Never Tell Me The Odds: crossing hailstones and the rock that hits them
+/// all.
+pub mod day24;
+/// Snowverload: the three wires whose cut splits the components in two.
+pub mod day25;
+
+/// Every day, type erased for the runner.
+pub const DAYS: [Day; 25] = [
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>(),
+    Day::new::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day11::Day11>(),
+    Day::new::<day12::Day12>(),
+    Day::new::<day13::Day13>(),
+    Day::new::<day14::Day14>(),
+    Day::new::<day15::Day15>(),
+    Day::new::<day16::Day16>(),
+    Day::new::<day17::Day17>(),
+    Day::new::<day18::Day18>(),
+    Day::new::<day19::Day19>(),
+    Day::new::<day20::Day20>(),
+    Day::new::<day21::Day21>(),
+    Day::new::<day22::Day22>(),
+    Day::new::<day23::Day23>(),
+    Day::new::<day24::Day24>(),
+    Day::new::<day25::Day25>(),
+];
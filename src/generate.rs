@@ -0,0 +1,165 @@
+use ahash::AHashSet;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::grid::{Direction, Grid, Point};
+
+/// The random number generator passed to
+/// [`Solution::generate`](crate::Solution::generate), so that a seed always
+/// produces the same input.
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// `count` distinct random words of `len` characters from `alphabet`.
+pub fn names(rng: &mut StdRng, count: usize, len: usize, alphabet: &str) -> Vec<String> {
+    let alphabet: Vec<char> = alphabet.chars().collect();
+    assert!(
+        (alphabet.len() as f64).powi(len as i32) >= 2.0 * count as f64,
+        "not enough names of length {len}"
+    );
+
+    let mut seen = AHashSet::new();
+    let mut names = vec![];
+
+    while names.len() < count {
+        let name: String = (0..len).map(|_| *alphabet.choose(rng).unwrap()).collect();
+        if seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    names
+}
+
+/// Shortest name length for which `alphabet_len` characters give enough
+/// distinct [`names`].
+pub fn name_len(count: usize, alphabet_len: usize) -> usize {
+    let mut len = 1;
+    while (alphabet_len as f64).powi(len as i32) < 2.0 * count as f64 {
+        len += 1;
+    }
+    len
+}
+
+/// Map with one character per tile, see [`Grid::parse`].
+pub fn grid_text(grid: &Grid<char>) -> String {
+    grid.rows()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+/// Random spanning tree on a `size` x `size` lattice of nodes, drawn on a
+/// grid of `2 * size - 1` tiles per side: nodes are the tiles with even
+/// coordinates and every tree edge fills the tile between its nodes.
+pub fn spanning_tree(rng: &mut StdRng, size: usize) -> Grid<bool> {
+    let size = size.max(1);
+    let side = 2 * size - 1;
+    let mut tree = Grid::filled(side, side, false);
+    let start = Point::new(
+        2 * rng.gen_range(0..size) as i64,
+        2 * rng.gen_range(0..size) as i64,
+    );
+    let mut stack = vec![start];
+    tree[start] = true;
+
+    // randomized depth first search, which gives long winding branches
+    while let Some(&node) = stack.last() {
+        let mut dirs = Direction::ALL;
+        dirs.shuffle(rng);
+
+        let next = dirs
+            .into_iter()
+            .map(|dir| (node.step(dir), node + dir.offset() * 2))
+            .find(|&(_, next)| tree.get(next) == Some(&false));
+
+        match next {
+            Some((edge, next)) => {
+                tree[edge] = true;
+                tree[next] = true;
+                stack.push(next);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    tree
+}
+
+/// Corners of the outline of the filled tiles of `shape`, in order. Tile
+/// `(x, y)` spans the corners `(x, y)` to `(x + 1, y + 1)`. The filled tiles
+/// must form a single region without holes in which no two tiles touch only
+/// diagonally, like a [`spanning_tree`], so that the outline is a simple
+/// polygon through every corner at most once.
+pub fn outline(shape: &Grid<bool>) -> Vec<Point> {
+    let filled = |x: i64, y: i64| shape.get(Point::new(x, y)) == Some(&true);
+    let start = shape.position(|&t| t).expect("the shape has a filled tile");
+
+    // walk clockwise with the filled tiles on the right hand side
+    let mut corners = vec![start];
+    let mut pos = start;
+    let mut dir = Direction::East;
+
+    loop {
+        // the tiles ahead left and ahead right of `pos` facing `dir`
+        let (left, right) = match dir {
+            Direction::East => ((pos.x, pos.y - 1), (pos.x, pos.y)),
+            Direction::South => ((pos.x, pos.y), (pos.x - 1, pos.y)),
+            Direction::West => ((pos.x - 1, pos.y), (pos.x - 1, pos.y - 1)),
+            Direction::North => ((pos.x - 1, pos.y - 1), (pos.x, pos.y - 1)),
+        };
+
+        // turning right is always followed by a step, the tile on the right
+        // is then the one we were walking along
+        dir = match (filled(left.0, left.1), filled(right.0, right.1)) {
+            (true, _) => dir.turn_left(),
+            (false, true) => dir,
+            (false, false) => dir.turn_right(),
+        };
+
+        pos = pos.step(dir);
+        if pos == start {
+            return corners;
+        }
+        corners.push(pos);
+    }
+}
+
+#[test]
+fn test_generate() {
+    let mut rng = rng(1);
+
+    let tree = spanning_tree(&mut rng, 4);
+    assert_eq!((tree.width(), tree.height()), (7, 7));
+    // a tree on 16 nodes has 15 edges
+    assert_eq!(tree.iter().filter(|(_, &t)| t).count(), 16 + 15);
+
+    let corners = outline(&tree);
+    let unique: AHashSet<_> = corners.iter().collect();
+    assert_eq!(unique.len(), corners.len());
+    assert!(corners
+        .iter()
+        .zip(corners.iter().cycle().skip(1))
+        .all(|(a, b)| a.manhattan(*b) == 1));
+    // a tree of 31 unit squares has an outline of length 2 * 31 + 2
+    assert_eq!(corners.len(), 64);
+
+    let names = names(&mut rng, 20, name_len(20, 4), "abcd");
+    let unique: AHashSet<_> = names.iter().collect();
+    assert_eq!((names[0].len(), unique.len()), (3, 20));
+
+    for day in crate::DAYS.iter() {
+        let input = day.generate(7, 20);
+        assert_eq!(input, day.generate(7, 20), "day {}", day.day);
+
+        let assumptions = day.validate(&input).unwrap();
+        assert!(assumptions.iter().all(|a| a.holds()), "day {}", day.day);
+
+        if let Err(err) = day.solve(&input, crate::Part::Both) {
+            panic!("day {}: {err}", day.day);
+        }
+    }
+}
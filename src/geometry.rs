@@ -0,0 +1,106 @@
+use crate::grid::Point;
+use crate::math::gcd;
+
+/// Turn direction of three consecutive points, in a coordinate system where
+/// `y` points up. On a [`Grid`](crate::grid::Grid), where `y` points down,
+/// the visual direction is mirrored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    CounterClockwise,
+    Clockwise,
+    Collinear,
+}
+
+/// Orientation of the turn `a -> b -> c`.
+pub fn orientation(a: Point, b: Point, c: Point) -> Orientation {
+    let cross = (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x);
+
+    match cross.signum() {
+        1 => Orientation::CounterClockwise,
+        -1 => Orientation::Clockwise,
+        _ => Orientation::Collinear,
+    }
+}
+
+// https://en.wikipedia.org/wiki/Shoelace_formula
+/// Twice the signed area of the polygon, positive if the vertices are
+/// ordered counterclockwise. The polygon is closed implicitly.
+pub fn double_signed_area(vertices: &[Point]) -> i64 {
+    edges(vertices).map(|(a, b)| a.x * b.y - a.y * b.x).sum()
+}
+
+/// Number of lattice points on the boundary of the polygon.
+pub fn boundary_points(vertices: &[Point]) -> i64 {
+    edges(vertices)
+        .map(|(a, b)| gcd(b.x - a.x, b.y - a.y).expect("boundary points overflow"))
+        .sum()
+}
+
+// https://en.wikipedia.org/wiki/Pick%27s_theorem
+/// Number of lattice points strictly inside the polygon, which must not
+/// intersect itself.
+pub fn interior_points(vertices: &[Point]) -> i64 {
+    let double_area = double_signed_area(vertices).abs();
+
+    (double_area - boundary_points(vertices) + 2) / 2
+}
+
+// https://wrfranklin.org/Research/Short_Notes/pnpoly.html
+/// Whether `point` lies inside the polygon. Points on the boundary may be
+/// reported either way.
+pub fn contains(vertices: &[Point], point: Point) -> bool {
+    let mut inside = false;
+
+    for (a, b) in edges(vertices) {
+        if (a.y > point.y) != (b.y > point.y) {
+            // point.x < x of the edge at point.y, without dividing
+            let (lhs, rhs) = ((point.x - a.x) * (b.y - a.y), (b.x - a.x) * (point.y - a.y));
+            if (b.y > a.y && lhs < rhs) || (b.y < a.y && lhs > rhs) {
+                inside = !inside;
+            }
+        }
+    }
+
+    inside
+}
+
+fn edges(vertices: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    vertices
+        .iter()
+        .copied()
+        .zip(vertices.iter().copied().cycle().skip(1))
+}
+
+#[test]
+fn test_geometry() {
+    let square = [
+        Point::new(0, 0),
+        Point::new(4, 0),
+        Point::new(4, 4),
+        Point::new(0, 4),
+    ];
+    let triangle = [Point::new(0, 0), Point::new(0, 3), Point::new(6, 0)];
+
+    assert_eq!(double_signed_area(&square), 32);
+    assert_eq!(double_signed_area(&triangle), -18);
+    assert_eq!(boundary_points(&square), 16);
+    assert_eq!(boundary_points(&triangle), 12);
+    assert_eq!(interior_points(&square), 9);
+    assert_eq!(interior_points(&triangle), 4);
+
+    assert!(contains(&square, Point::new(1, 2)));
+    assert!(!contains(&square, Point::new(5, 2)));
+    assert!(!contains(&triangle, Point::new(3, 2)));
+    assert!(contains(&triangle, Point::new(3, 1)));
+    let slanted = [Point::new(0, 0), Point::new(3, 2), Point::new(0, 2)];
+    assert!(contains(&slanted, Point::new(1, 1)));
+    assert!(!contains(&slanted, Point::new(2, 1)));
+
+    let (a, b) = (Point::new(0, 0), Point::new(2, 0));
+    assert_eq!(
+        orientation(a, b, Point::new(1, 1)),
+        Orientation::CounterClockwise
+    );
+    assert_eq!(orientation(a, b, Point::new(1, -1)), Orientation::Clockwise);
+    assert_eq!(orientation(a, b, Point::new(4, 0)), Orientation::Collinear);
+}
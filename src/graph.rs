@@ -0,0 +1,372 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+use ahash::AHashMap;
+
+use crate::cancel;
+
+/// Directed graph with weighted edges between the nodes `0..len`, stored as
+/// adjacency lists. Undirected graphs store every edge in both directions.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Graph {
+    adjacency: Vec<Vec<(usize, i64)>>,
+}
+
+impl Graph {
+    pub fn new(len: usize) -> Graph {
+        Graph {
+            adjacency: vec![vec![]; len],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.adjacency.is_empty()
+    }
+
+    /// Adds a node without edges and returns it.
+    pub fn add_node(&mut self) -> usize {
+        self.adjacency.push(vec![]);
+        self.adjacency.len() - 1
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: i64) {
+        self.adjacency[from].push((to, weight));
+    }
+
+    pub fn add_undirected_edge(&mut self, a: usize, b: usize, weight: i64) {
+        self.add_edge(a, b, weight);
+        self.add_edge(b, a, weight);
+    }
+
+    /// Outgoing edges of `node` as `(to, weight)`.
+    pub fn neighbors(&self, node: usize) -> &[(usize, i64)] {
+        &self.adjacency[node]
+    }
+
+    /// All edges as `(from, to, weight)`.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, i64)> + '_ {
+        self.adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(from, to)| to.iter().map(move |&(to, w)| (from, to, w)))
+    }
+
+    /// Replaces corridors, chains of nodes with exactly two neighbours, by a
+    /// single edge with the summed weight. Nodes with any other number of
+    /// neighbours and nodes for which `keep` returns true remain. Returns the
+    /// contracted graph and the original node of each of its nodes.
+    pub fn contract<K>(&self, keep: K) -> (Graph, Vec<usize>)
+    where
+        K: Fn(usize) -> bool,
+    {
+        let mut neighbors = vec![vec![]; self.len()];
+        for (from, to, _) in self.edges() {
+            for (a, b) in [(from, to), (to, from)] {
+                if a != b && !neighbors[a].contains(&b) {
+                    neighbors[a].push(b);
+                }
+            }
+        }
+
+        let kept: Vec<_> = (0..self.len())
+            .filter(|&n| keep(n) || neighbors[n].len() != 2)
+            .collect();
+        let mut ids = vec![None; self.len()];
+        for (id, &node) in kept.iter().enumerate() {
+            ids[node] = Some(id);
+        }
+
+        let mut contracted = Graph::new(kept.len());
+
+        for (from, &node) in kept.iter().enumerate() {
+            for &(next, weight) in self.neighbors(node) {
+                let (mut prev, mut curr, mut length) = (node, next, weight);
+
+                loop {
+                    if let Some(to) = ids[curr] {
+                        contracted.add_edge(from, to, length);
+                        break;
+                    }
+
+                    // a corridor node has at most one neighbour besides `prev`
+                    match self.neighbors(curr).iter().find(|&&(n, _)| n != prev) {
+                        Some(&(n, w)) => (prev, curr, length) = (curr, n, length + w),
+                        None => break, // dead end or one-way corridor
+                    }
+                }
+            }
+        }
+
+        (contracted, kept)
+    }
+
+    /// Longest path from `start` to `goal` that visits every node at most
+    /// once, found by exhaustive search. Only graphs with up to 64 nodes are
+    /// supported, so contract corridors first. The search is a
+    /// [`cancel::check`] point.
+    pub fn longest_path(&self, start: usize, goal: usize) -> Option<Path<usize>> {
+        fn dfs(
+            graph: &Graph,
+            goal: usize,
+            visited: u64,
+            path: &mut Path<usize>,
+            best: &mut Option<Path<usize>>,
+        ) {
+            let node = *path.nodes.last().unwrap();
+            cancel::check();
+
+            if node == goal {
+                if best.as_ref().is_none_or(|b| path.cost > b.cost) {
+                    *best = Some(path.clone());
+                }
+                return;
+            }
+
+            for &(next, w) in graph.neighbors(node) {
+                if visited & 1 << next == 0 {
+                    path.nodes.push(next);
+                    path.cost += w;
+                    dfs(graph, goal, visited | 1 << next, path, best);
+                    path.cost -= w;
+                    path.nodes.pop();
+                }
+            }
+        }
+
+        assert!(
+            self.len() <= 64,
+            "longest path search is limited to 64 nodes"
+        );
+
+        let mut path = Path {
+            nodes: vec![start],
+            cost: 0,
+        };
+        let mut best = None;
+        dfs(self, goal, 1 << start, &mut path, &mut best);
+
+        best
+    }
+
+    // https://en.wikipedia.org/wiki/Stoer%E2%80%93Wagner_algorithm
+    /// Global minimum cut of an undirected graph. Returns the summed weight of
+    /// the cut edges and the nodes on one side of the cut, or `None` for
+    /// graphs with less than two nodes.
+    pub fn min_cut(&self) -> Option<(i64, Vec<usize>)> {
+        let len = self.len();
+        if len < 2 {
+            return None;
+        }
+
+        let mut adjacency = vec![AHashMap::new(); len];
+        for (from, to, weight) in self.edges().filter(|(a, b, _)| a != b) {
+            *adjacency[from].entry(to).or_insert(0) += weight;
+        }
+
+        let mut merged: Vec<_> = (0..len).map(|n| vec![n]).collect();
+        let mut active: Vec<_> = (0..len).collect();
+        let mut best: Option<(i64, Vec<usize>)> = None;
+
+        while active.len() > 1 {
+            // maximum adjacency ordering, the last two nodes are s and t
+            let mut weights = vec![0; len];
+            let mut added = vec![false; len];
+            let mut heap = BinaryHeap::from([(0, active[0])]);
+            let (mut s, mut t, mut cut) = (None, active[0], 0);
+            let mut count = 0;
+
+            while let Some((weight, node)) = heap.pop() {
+                if added[node] || weight != weights[node] {
+                    continue;
+                }
+
+                added[node] = true;
+                count += 1;
+                (s, t, cut) = (Some(t).filter(|_| count > 1), node, weight);
+
+                for (&next, &w) in &adjacency[node] {
+                    if !added[next] {
+                        weights[next] += w;
+                        heap.push((weights[next], next));
+                    }
+                }
+            }
+
+            if count < active.len() {
+                // disconnected, the reached nodes are cut off for free
+                let side = active.iter().filter(|&&n| added[n]);
+                return Some((0, side.flat_map(|&n| merged[n].clone()).collect()));
+            }
+
+            if best.as_ref().is_none_or(|(b, _)| cut < *b) {
+                best = Some((cut, merged[t].clone()));
+            }
+
+            let s = s.expect("a phase adds at least two nodes");
+            let t_merged = std::mem::take(&mut merged[t]);
+            merged[s].extend(t_merged);
+
+            for (next, w) in std::mem::take(&mut adjacency[t]) {
+                adjacency[next].remove(&t);
+                if next != s {
+                    *adjacency[s].entry(next).or_insert(0) += w;
+                    *adjacency[next].entry(s).or_insert(0) += w;
+                }
+            }
+            active.retain(|&n| n != t);
+        }
+
+        best
+    }
+}
+
+/// Nodes of a path including start and goal, and its cost.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N> {
+    pub nodes: Vec<N>,
+    pub cost: i64,
+}
+
+// https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
+/// Shortest path from any of `starts` to a node satisfying `is_goal`.
+/// `successors` returns the reachable nodes with the non-negative cost of the
+/// step, so the graph can be implicit, e.g. states of a puzzle.
+pub fn dijkstra<N, S, I, G>(
+    starts: impl IntoIterator<Item = N>,
+    successors: S,
+    is_goal: G,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, i64)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(starts, successors, |_| 0, is_goal)
+}
+
+// https://en.wikipedia.org/wiki/A*_search_algorithm
+/// [`dijkstra`] guided by `heuristic`, which must never overestimate the
+/// remaining cost to a goal.
+pub fn astar<N, S, I, H, G>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: S,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, i64)>,
+    H: FnMut(&N) -> i64,
+    G: FnMut(&N) -> bool,
+{
+    // (node, parent, cost), nodes are referred to by their index
+    let mut nodes: Vec<(N, Option<usize>, i64)> = vec![];
+    let mut index = AHashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if !index.contains_key(&start) {
+            index.insert(start.clone(), nodes.len());
+            heap.push(Reverse((heuristic(&start), 0, nodes.len())));
+            nodes.push((start, None, 0));
+        }
+    }
+
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        if cost > nodes[i].2 {
+            continue; // a cheaper path was found after this entry was pushed
+        }
+
+        if is_goal(&nodes[i].0) {
+            let mut path = vec![];
+            let mut curr = Some(i);
+            while let Some(c) = curr {
+                path.push(nodes[c].0.clone());
+                curr = nodes[c].1;
+            }
+            path.reverse();
+
+            return Some(Path { nodes: path, cost });
+        }
+
+        let node = nodes[i].0.clone();
+        for (next, weight) in successors(&node) {
+            let next_cost = cost + weight;
+
+            let j = match index.get(&next) {
+                Some(&j) if nodes[j].2 <= next_cost => continue,
+                Some(&j) => {
+                    nodes[j].1 = Some(i);
+                    nodes[j].2 = next_cost;
+                    j
+                }
+                None => {
+                    index.insert(next.clone(), nodes.len());
+                    nodes.push((next.clone(), Some(i), next_cost));
+                    nodes.len() - 1
+                }
+            };
+
+            heap.push(Reverse((next_cost + heuristic(&next), next_cost, j)));
+        }
+    }
+
+    None
+}
+
+#[test]
+fn test_graph() {
+    // 0 - 1 - 2 - 3 with a shortcut 0 - 3 and a dead end 2 - 4
+    let mut graph = Graph::new(5);
+    for (a, b, w) in [(0, 1, 1), (1, 2, 2), (2, 3, 3), (0, 3, 10), (2, 4, 1)] {
+        graph.add_undirected_edge(a, b, w);
+    }
+
+    let path = dijkstra([0], |&n| graph.neighbors(n).to_vec(), |&n| n == 3);
+    assert_eq!(
+        path,
+        Some(Path {
+            nodes: vec![0, 1, 2, 3],
+            cost: 6
+        })
+    );
+    assert_eq!(graph.longest_path(0, 3).map(|p| p.cost), Some(10));
+    let path = graph.longest_path(4, 0).unwrap();
+    assert_eq!((path.nodes, path.cost), (vec![4, 2, 3, 0], 14));
+
+    let (contracted, kept) = graph.contract(|n| n == 0);
+    assert_eq!(kept, [0, 2, 4]);
+    assert_eq!(contracted.neighbors(0), [(1, 3), (1, 13)]);
+    assert_eq!(contracted.longest_path(0, 2).map(|p| p.cost), Some(14));
+
+    // A* on a 5x5 grid around a wall at x = 2, y < 4
+    let successors = |&(x, y): &(i64, i64)| {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y))
+            .filter(|&(x, y)| x != 2 || y == 4)
+            .map(|p| (p, 1))
+    };
+    let manhattan = |&(x, y): &(i64, i64)| (4 - x).abs() + y.abs();
+    let path = astar([(0, 0)], successors, manhattan, |&p| p == (4, 0)).unwrap();
+    assert_eq!(path.cost, 12);
+    assert_eq!(path.nodes.len(), 13);
+
+    // two triangles joined by a single edge
+    let mut graph = Graph::new(6);
+    for (a, b) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)] {
+        graph.add_undirected_edge(a, b, 1);
+    }
+
+    let (cut, mut side) = graph.min_cut().unwrap();
+    side.sort();
+    assert_eq!(cut, 1);
+    assert!(side == [0, 1, 2] || side == [3, 4, 5]);
+}
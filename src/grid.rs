@@ -0,0 +1,370 @@
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Sub};
+
+use crate::parse::ParseError;
+
+/// Position on a [`Grid`]. `x` grows to the east and `y` to the south. The
+/// coordinates are signed so that positions outside the grid (neighbours of
+/// the border, tiles of an infinitely repeated grid) can be represented.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    pub fn step(self, dir: Direction) -> Point {
+        self + dir.offset()
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The orthogonal neighbours in the order of [`Direction::ALL`].
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |dir| self.step(dir))
+    }
+
+    /// The orthogonal and diagonal neighbours, clockwise from north.
+    pub fn neighbors8(self) -> impl Iterator<Item = Point> {
+        [
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+        ]
+        .into_iter()
+        .map(move |(x, y)| self + Point::new(x, y))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, n: i64) -> Point {
+        Point::new(self.x * n, self.y * n)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::South => Point::new(0, 1),
+            Direction::West => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::East | Direction::West)
+    }
+}
+
+/// Rectangular map of tiles stored row by row in a single `Vec`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    tiles: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its tiles in row-major order.
+    pub fn new(width: usize, height: usize, tiles: Vec<T>) -> Grid<T> {
+        assert_eq!(width * height, tiles.len(), "tiles do not fill the grid");
+
+        Grid {
+            width,
+            height,
+            tiles,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, tile: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![tile; width * height])
+    }
+
+    /// Parses `text`, a slice of `input`, with one tile per character and one
+    /// row per line. `tile` returns `None` for characters that are not a
+    /// valid tile, which is reported as `expected`.
+    pub fn parse_with<F>(
+        input: &str,
+        text: &str,
+        expected: &str,
+        mut tile: F,
+    ) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut tiles = vec![];
+
+        for line in text.lines() {
+            let start = tiles.len();
+
+            for (i, c) in line.char_indices() {
+                match tile(c) {
+                    Some(t) => tiles.push(t),
+                    None => return Err(ParseError::at(input, &line[i..], expected)),
+                }
+            }
+
+            let len = tiles.len() - start;
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    let end = line.char_indices().nth(width);
+                    let token = &line[end.map_or(line.len(), |(i, _)| i)..];
+                    let expected = format!("row of {width} tiles");
+                    return Err(ParseError::at(input, token, expected));
+                }
+                Some(_) => (),
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, tiles))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        0 <= p.x && p.x < self.width as i64 && 0 <= p.y && p.y < self.height as i64
+    }
+
+    fn offset(&self, p: Point) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.offset(p).map(|i| &self.tiles[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.tiles[i])
+    }
+
+    /// Access to the grid repeated infinitely in all directions.
+    pub fn get_wrapped(&self, p: Point) -> &T {
+        let x = p.x.rem_euclid(self.width as i64);
+        let y = p.y.rem_euclid(self.height as i64);
+
+        &self[Point::new(x, y)]
+    }
+
+    /// All positions in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width as i64, self.height as i64);
+
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.tiles)
+    }
+
+    /// Position of the first tile matching `pred` in row-major order.
+    pub fn position<P>(&self, pred: P) -> Option<Point>
+    where
+        P: Fn(&T) -> bool,
+    {
+        self.iter().find(|(_, t)| pred(t)).map(|(p, _)| p)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.tiles[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {x} is out of bounds");
+
+        self.tiles.iter().skip(x).step_by(self.width)
+    }
+
+    /// Orthogonal neighbours of `p` that lie inside the grid.
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors4().filter(|&n| self.contains(n))
+    }
+
+    /// Orthogonal and diagonal neighbours of `p` that lie inside the grid.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors8().filter(|&n| self.contains(n))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid::new(self.width, self.height, self.tiles.iter().map(f).collect())
+    }
+
+    /// Mirrors the grid along its main diagonal.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let tiles = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+
+        Grid::new(self.height, self.width, tiles)
+    }
+
+    /// Rotates the grid by 90 degrees clockwise.
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let tiles = (0..self.width)
+            .flat_map(|x| self.column(x).rev().cloned())
+            .collect();
+
+        Grid::new(self.height, self.width, tiles)
+    }
+
+    /// Rotates the grid by 90 degrees counterclockwise.
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let tiles = (0..self.width)
+            .rev()
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+
+        Grid::new(self.height, self.width, tiles)
+    }
+}
+
+impl Grid<char> {
+    /// Parses a map with one character per tile.
+    pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse_with(input, input, "tile", Some)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        match self.offset(p) {
+            Some(i) => &self.tiles[i],
+            None => panic!("{p:?} is outside of the grid"),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        match self.offset(p) {
+            Some(i) => &mut self.tiles[i],
+            None => panic!("{p:?} is outside of the grid"),
+        }
+    }
+}
+
+#[test]
+fn test_grid() {
+    let input = "abc\ndef\n";
+    let grid = Grid::parse(input).unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Point::new(2, 1)], 'f');
+    assert_eq!(grid.get(Point::new(3, 0)), None);
+    assert_eq!(grid.get(Point::new(0, -1)), None);
+    assert_eq!(*grid.get_wrapped(Point::new(-1, 5)), 'f');
+    assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    assert_eq!(grid.column(1).collect::<String>(), "be");
+    assert_eq!(grid.position(|&c| c == 'e'), Some(Point::new(1, 1)));
+    assert_eq!(grid.neighbors4(Point::new(0, 0)).count(), 2);
+    assert_eq!(grid.neighbors8(Point::new(1, 0)).count(), 5);
+
+    let rows = |grid: Grid<char>| grid.rows().map(String::from_iter).collect::<Vec<_>>();
+    assert_eq!(rows(grid.transpose()), ["ad", "be", "cf"]);
+    assert_eq!(rows(grid.rotate_cw()), ["da", "eb", "fc"]);
+    assert_eq!(rows(grid.rotate_ccw()), ["cf", "be", "ad"]);
+
+    assert_eq!(Direction::North.turn_left(), Direction::West);
+    assert_eq!(Direction::West.turn_right(), Direction::North);
+    assert_eq!(Direction::East.reverse(), Direction::West);
+
+    let err = Grid::parse("ab\nc\n").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 2));
+    let err = Grid::parse_with("12\n3x", "12\n3x", "digit", |c| c.to_digit(10)).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 2));
+}
@@ -1,5 +1,423 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::ops::ControlFlow;
 use std::path::PathBuf;
+use std::process;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+
+pub mod animate;
+pub mod bench;
+pub mod cancel;
+pub mod cycle;
+/// One module per day. Each has a `DayNN` type implementing [`Solution`],
+/// which parses the input into the day's puzzle type (like
+/// [`days::day05::Almanac`] or [`days::day22::Brick`]) and solves both parts.
+pub mod days;
+pub mod generate;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod math;
+pub mod output;
+pub mod parse;
+pub mod property;
+pub mod render;
+pub mod validate;
+pub mod verify;
+
+use animate::Frames;
+pub use days::DAYS;
+pub use parse::ParseError;
+use render::Canvas;
+use validate::Assumption;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+    Both,
+}
+
+impl Part {
+    pub fn part1(self) -> bool {
+        self != Part::Two
+    }
+
+    pub fn part2(self) -> bool {
+        self != Part::One
+    }
+}
+
+/// Answer of a single puzzle part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    /// The part has no puzzle (day 25 part 2).
+    None,
+}
+
+macro_rules! impl_answer_from {
+    ($variant:ident, $target:ty, $($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::$variant(value as $target)
+            }
+        })*
+    };
+}
+
+impl_answer_from!(Int, i64, i32, i64);
+impl_answer_from!(UInt, u64, u32, u64, usize);
+
+impl From<()> for Answer {
+    fn from(_: ()) -> Self {
+        Answer::None
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::UInt(n) => write!(f, "{n}"),
+            Answer::None => Ok(()),
+        }
+    }
+}
+
+/// Common shape of every day: the input is parsed once and then passed to
+/// both parts. Puzzle parameters that differ between the examples and the
+/// real input (step counts, bounds, ...) live in `Config`.
+pub trait Solution {
+    const DAY: u32;
+
+    type Input<'a>;
+    type Config: Default;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>, config: &Self::Config) -> Self::Answer1;
+    fn part2(input: &Self::Input<'_>, config: &Self::Config) -> Self::Answer2;
+
+    /// Picture of the puzzle for `aoc --render`, `None` for days that are not
+    /// drawn on a grid.
+    fn render(_input: &Self::Input<'_>, _config: &Self::Config) -> Option<Canvas> {
+        None
+    }
+
+    /// Passes the intermediate states of a simulation to `frames` for
+    /// `aoc animate`. Days that are not simulations show no frames.
+    fn animate(
+        _input: &Self::Input<'_>,
+        _config: &Self::Config,
+        _frames: &mut Frames<'_>,
+    ) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Checks the properties of the official inputs that the solution relies
+    /// on for `aoc validate`, so that an input breaking them is reported
+    /// instead of giving a wrong answer.
+    fn validate(_input: &Self::Input<'_>, _config: &Self::Config) -> Vec<Assumption> {
+        vec![]
+    }
+
+    /// Config of the days that use randomness for `aoc --seed`, the other
+    /// days ignore the seed.
+    fn seeded(_seed: u64) -> Self::Config {
+        Self::Config::default()
+    }
+
+    /// Sets the config parameter `key` to `value`, as given by the answers
+    /// file of an example in `examples/`.
+    fn configure(_config: &mut Self::Config, key: &str, _value: &str) -> Result<(), String> {
+        Err(format!("unknown parameter {key}"))
+    }
+
+    /// Statistics about solving `input` for `aoc --stats`, as name and value.
+    fn stats(_input: &Self::Input<'_>, _config: &Self::Config) -> Vec<(&'static str, String)> {
+        vec![]
+    }
+
+    /// Random input of roughly `size` lines, tiles or items per side for
+    /// `aoc generate`. `rng` comes from [`generate::rng`].
+    fn generate(rng: &mut StdRng, size: usize) -> String;
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (n, answer) in [(1, &self.part1), (2, &self.part2)] {
+            match answer {
+                Some(Answer::None) | None => (),
+                Some(answer) => writeln!(f, "part{n} = {answer}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Time spent in each phase of a [`solve`] run. Parts that were not selected
+/// are `None`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+/// Parses `input` after [`parse::normalize`] and solves the selected parts of
+/// `S`.
+pub fn solve<S: Solution>(
+    input: &str,
+    part: Part,
+    config: &S::Config,
+) -> Result<(Answers, Timings), ParseError> {
+    let mut answers = Answers::default();
+    let mut timings = Timings::default();
+
+    let start = Instant::now();
+    let input = parse::normalize(input);
+    let input = S::parse(&input)?;
+    timings.parse = start.elapsed();
+
+    if part.part1() {
+        let start = Instant::now();
+        answers.part1 = Some(S::part1(&input, config).into());
+        timings.part1 = Some(start.elapsed());
+    }
+    if part.part2() {
+        let start = Instant::now();
+        answers.part2 = Some(S::part2(&input, config).into());
+        timings.part2 = Some(start.elapsed());
+    }
+
+    Ok((answers, timings))
+}
+
+type Run = fn(&str, Part, Option<u64>) -> Result<(Answers, Timings), ParseError>;
+type Render = fn(&str) -> Result<Option<Canvas>, ParseError>;
+type Animate = fn(&str, &mut Frames<'_>) -> Result<(), ParseError>;
+type Validate = fn(&str) -> Result<Vec<Assumption>, ParseError>;
+type Stats = fn(&str, Option<u64>) -> Result<Vec<(&'static str, String)>, ParseError>;
+type Generate = fn(u64, usize) -> String;
+type Configured = fn(&str, Part, &[(&str, &str)]) -> Result<Answers, String>;
+
+/// Type erased [`Solution`] with its default config, or the one of
+/// [`Solution::seeded`] when a seed is given, see [`DAYS`]. Every input is
+/// [`parse::normalize`]d before it is parsed.
+pub struct Day {
+    pub day: u32,
+    run: Run,
+    render: Render,
+    animate: Animate,
+    validate: Validate,
+    stats: Stats,
+    generate: Generate,
+    configured: Configured,
+}
+
+fn config<S: Solution>(seed: Option<u64>) -> S::Config {
+    seed.map_or_else(S::Config::default, S::seeded)
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Day {
+        Day {
+            day: S::DAY,
+            run: |input, part, seed| solve::<S>(input, part, &config::<S>(seed)),
+            render: |input| {
+                Ok(S::render(
+                    &S::parse(&parse::normalize(input))?,
+                    &S::Config::default(),
+                ))
+            },
+            animate: |input, frames| {
+                let _ = S::animate(
+                    &S::parse(&parse::normalize(input))?,
+                    &S::Config::default(),
+                    frames,
+                );
+                Ok(())
+            },
+            validate: |input| {
+                Ok(S::validate(
+                    &S::parse(&parse::normalize(input))?,
+                    &S::Config::default(),
+                ))
+            },
+            stats: |input, seed| {
+                Ok(S::stats(
+                    &S::parse(&parse::normalize(input))?,
+                    &config::<S>(seed),
+                ))
+            },
+            generate: |seed, size| S::generate(&mut generate::rng(seed), size),
+            configured: |input, part, params| {
+                let mut config = S::Config::default();
+                for (key, value) in params {
+                    S::configure(&mut config, key, value)?;
+                }
+                solve::<S>(input, part, &config)
+                    .map(|(answers, _)| answers)
+                    .map_err(|err| err.to_string())
+            },
+        }
+    }
+
+    pub fn solve(&self, input: &str, part: Part) -> Result<Answers, ParseError> {
+        Ok((self.run)(input, part, None)?.0)
+    }
+
+    pub fn time(&self, input: &str, part: Part) -> Result<(Answers, Timings), ParseError> {
+        (self.run)(input, part, None)
+    }
+
+    /// [`Day::time`] with the config of [`Solution::seeded`] if a seed is
+    /// given.
+    pub fn time_seeded(
+        &self,
+        input: &str,
+        part: Part,
+        seed: Option<u64>,
+    ) -> Result<(Answers, Timings), ParseError> {
+        (self.run)(input, part, seed)
+    }
+
+    /// [`Day::solve`] with the default config changed by the `key = value`
+    /// parameters of [`Solution::configure`]. Unknown parameters and parse
+    /// errors are reported as messages.
+    pub fn solve_with(
+        &self,
+        input: &str,
+        part: Part,
+        params: &[(&str, &str)],
+    ) -> Result<Answers, String> {
+        (self.configured)(input, part, params)
+    }
+
+    /// See [`Solution::render`].
+    pub fn render(&self, input: &str) -> Result<Option<Canvas>, ParseError> {
+        (self.render)(input)
+    }
+
+    /// See [`Solution::animate`].
+    pub fn animate(&self, input: &str, frames: &mut Frames<'_>) -> Result<(), ParseError> {
+        (self.animate)(input, frames)
+    }
+
+    /// See [`Solution::validate`].
+    pub fn validate(&self, input: &str) -> Result<Vec<Assumption>, ParseError> {
+        (self.validate)(input)
+    }
+
+    /// See [`Solution::stats`].
+    pub fn stats(
+        &self,
+        input: &str,
+        seed: Option<u64>,
+    ) -> Result<Vec<(&'static str, String)>, ParseError> {
+        (self.stats)(input, seed)
+    }
+
+    /// See [`Solution::generate`], the same `seed` always gives the same
+    /// input.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(seed, size)
+    }
+}
+
+/// Parses the value of a config parameter for [`Solution::configure`].
+pub(crate) fn param<T: FromStr>(key: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value of {key}: {value}"))
+}
+
+pub fn day(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    day: u32,
+    source: String,
+    error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot read input of day {:02} from {}: {}",
+            self.day, self.source, self.error
+        )
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
 
 pub fn input_file(filename: &str) -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR").to_owned() + "/input/" + filename)
 }
+
+/// Reads the input of `day` from `path`, from stdin if `path` is `-` or from
+/// `input/inputNN.txt` if no path is given.
+pub fn read_input(day: u32, path: Option<&str>) -> Result<String, InputError> {
+    let error = |source: String, error| InputError { day, source, error };
+
+    match path {
+        Some("-") => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| error("stdin".to_string(), e))?;
+            Ok(input)
+        }
+        Some(path) => fs::read_to_string(path).map_err(|e| error(path.to_string(), e)),
+        None => {
+            let path = input_file(&format!("input{day:02}.txt"));
+            fs::read_to_string(&path).map_err(|e| error(path.display().to_string(), e))
+        }
+    }
+}
+
+/// Entry point of the `dayNN` binaries. The input path can be passed as the
+/// first argument.
+pub fn day_main(day: u32) {
+    let path = std::env::args().nth(1);
+
+    let Some(solution) = self::day(day) else {
+        eprintln!("error: day {day} is not solved");
+        process::exit(1);
+    };
+
+    let input = read_input(day, path.as_deref()).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        process::exit(1);
+    });
+
+    match solution.solve(&input, Part::Both) {
+        Ok(answers) => print!("{answers}"),
+        Err(err) => {
+            eprintln!("error: day {day:02}: {err}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use std::fmt;
+use std::ops::{Div, Rem};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MathError {
+    /// The result does not fit into the integer type.
+    Overflow,
+    /// The congruences passed to [`crt`] contradict each other.
+    NoSolution,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathError::Overflow => write!(f, "arithmetic overflow"),
+            MathError::NoSolution => write!(f, "congruences have no solution"),
+        }
+    }
+}
+
+impl std::error::Error for MathError {}
+
+/// Primitive integers accepted by [`gcd`] and [`lcm`].
+pub trait Integer: Copy + Ord + Div<Output = Self> + Rem<Output = Self> {
+    const ZERO: Self;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+
+            fn checked_rem(self, other: Self) -> Option<Self> {
+                <$t>::checked_rem(self, other)
+            }
+        })*
+    };
+}
+
+impl_integer!(i32, i64, i128, isize, u32, u64, u128, usize);
+
+fn abs<T: Integer>(n: T) -> Result<T, MathError> {
+    if n < T::ZERO {
+        T::ZERO.checked_sub(n).ok_or(MathError::Overflow)
+    } else {
+        Ok(n)
+    }
+}
+
+// https://en.wikipedia.org/wiki/Euclidean_algorithm
+/// Greatest common divisor, always non-negative. `gcd(0, 0)` is 0. Overflows
+/// only if the result is the negated minimal signed integer.
+pub fn gcd<T: Integer>(mut a: T, mut b: T) -> Result<T, MathError> {
+    while b != T::ZERO {
+        let t = b;
+        // only `MIN % -1` fails here, which is 0
+        b = a.checked_rem(b).unwrap_or(T::ZERO);
+        a = t;
+    }
+
+    abs(a)
+}
+
+// https://en.wikipedia.org/wiki/Least_common_multiple
+/// Least common multiple, always non-negative. Divides before multiplying so
+/// that only results which do not fit into `T` overflow.
+pub fn lcm<T: Integer>(a: T, b: T) -> Result<T, MathError> {
+    if a == T::ZERO || b == T::ZERO {
+        return Ok(T::ZERO);
+    }
+
+    let lcm = (a / gcd(a, b)?).checked_mul(b).ok_or(MathError::Overflow)?;
+
+    abs(lcm)
+}
+
+// https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm
+/// Returns `(g, x, y)` with `g = gcd(a, b) = a * x + b * y`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a as i128, b as i128);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (old_r, old_x, old_y) = (-old_r, -old_x, -old_y);
+    }
+
+    (old_r as i64, old_x as i64, old_y as i64)
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    assert!(m > 0, "modulus must be positive");
+
+    match extended_gcd(a.rem_euclid(m), m) {
+        (1, x, _) => Some(x.rem_euclid(m)),
+        _ => None,
+    }
+}
+
+// https://en.wikipedia.org/wiki/Chinese_remainder_theorem
+/// Solves the system `x ≡ residue (mod modulus)` for all given
+/// `(residue, modulus)` pairs. The moduli need not be coprime. Returns the
+/// smallest non-negative solution and the lcm of the moduli, i.e. all
+/// solutions are `x + k * lcm`.
+pub fn crt(congruences: &[(i64, i64)]) -> Result<(i64, i64), MathError> {
+    let (mut x, mut m) = (0i128, 1i128);
+
+    for &(residue, modulus) in congruences {
+        assert!(modulus > 0, "modulus must be positive");
+
+        let (r2, m2) = (
+            (residue as i128).rem_euclid(modulus as i128),
+            modulus as i128,
+        );
+        let g = gcd(m, m2)?;
+
+        if (r2 - x) % g != 0 {
+            return Err(MathError::NoSolution);
+        }
+
+        // x + m * k ≡ r2 (mod m2)  <=>  (m / g) * k ≡ (r2 - x) / g (mod m2 / g)
+        let m2_g = m2 / g;
+        let inv = mod_inverse((m / g % m2_g) as i64, m2_g as i64)
+            .expect("m / g and m2 / g are coprime") as i128;
+        let k = ((r2 - x) / g).rem_euclid(m2_g) * inv % m2_g;
+
+        let lcm = m * m2_g;
+        if lcm > i64::MAX as i128 {
+            return Err(MathError::Overflow);
+        }
+
+        x = (x + m * k).rem_euclid(lcm);
+        m = lcm;
+    }
+
+    Ok((x as i64, m as i64))
+}
+
+#[test]
+fn test_math() {
+    assert_eq!(gcd(12, 18), Ok(6));
+    assert_eq!(gcd(-12i64, 18), Ok(6));
+    assert_eq!(gcd(0u32, 0), Ok(0));
+    assert_eq!(gcd(i64::MIN, -1), Ok(1));
+    assert_eq!(gcd(i64::MIN, 6), Ok(2));
+    assert_eq!(gcd(i64::MIN, 0), Err(MathError::Overflow));
+    assert_eq!(gcd(i64::MIN, i64::MIN), Err(MathError::Overflow));
+    assert_eq!(lcm(4usize, 6), Ok(12));
+    assert_eq!(lcm(-4i32, 6), Ok(12));
+    assert_eq!(lcm(u64::MAX, u64::MAX), Ok(u64::MAX));
+    assert_eq!(lcm(u64::MAX, u64::MAX - 1), Err(MathError::Overflow));
+
+    let (g, x, y) = extended_gcd(240, 46);
+    assert_eq!((g, 240 * x + 46 * y), (2, 2));
+
+    assert_eq!(mod_inverse(3, 11), Some(4));
+    assert_eq!(mod_inverse(-3, 11), Some(7));
+    assert_eq!(mod_inverse(4, 8), None);
+
+    assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+    assert_eq!(crt(&[(3, 4), (5, 6)]), Ok((11, 12)));
+    assert_eq!(crt(&[(1, 4), (2, 6)]), Err(MathError::NoSolution));
+    assert_eq!(crt(&[]), Ok((0, 1)));
+    assert_eq!(
+        crt(&[(0, i64::MAX), (1, i64::MAX - 1)]),
+        Err(MathError::Overflow)
+    );
+}
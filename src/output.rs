@@ -0,0 +1,293 @@
+use std::fmt::Write as _;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::cancel::{self, Abort};
+use crate::{Answer, Day, Part};
+
+/// How `aoc` prints the answers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// `part1 = ...` lines.
+    #[default]
+    Text,
+    Json,
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            _ => Err(format!("unknown output format: {s}")),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The input could not be read.
+    Input,
+    Parse,
+    Panic,
+    Timeout,
+}
+
+impl ErrorKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            ErrorKind::Input => "input",
+            ErrorKind::Parse => "parse",
+            ErrorKind::Panic => "panic",
+            ErrorKind::Timeout => "timeout",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    pub kind: ErrorKind,
+    pub message: String,
+}
+
+/// Result of one part of one day on one input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub day: u32,
+    pub part: u8,
+    /// The input as given to `aoc`, e.g. `input05.txt` or `alice/input05.txt`.
+    pub input: String,
+    /// The answer and the time spent in the part, without parsing.
+    pub result: Result<(Answer, Duration), Error>,
+}
+
+fn parts(part: Part) -> impl Iterator<Item = (u8, Part)> {
+    [(1, Part::One), (2, Part::Two)]
+        .into_iter()
+        .filter(move |&(_, p)| p == part || part == Part::Both)
+}
+
+/// Solves the selected parts of `day` one at a time, so that a panic in part
+/// 2 still gives the answer of part 1. Both parts share the deadline of an
+/// enclosing [`cancel::isolate`] call.
+pub fn solve(day: &Day, input: &str, name: &str, part: Part, seed: Option<u64>) -> Vec<Record> {
+    parts(part)
+        .map(|(n, part)| {
+            let result = match cancel::isolate(None, || day.time_seeded(input, part, seed)) {
+                Err(Abort::Panicked(message)) => Err(Error {
+                    kind: ErrorKind::Panic,
+                    message,
+                }),
+                Err(abort @ Abort::TimedOut(_)) => Err(Error {
+                    kind: ErrorKind::Timeout,
+                    message: abort.to_string(),
+                }),
+                Ok(Err(err)) => Err(Error {
+                    kind: ErrorKind::Parse,
+                    message: err.to_string(),
+                }),
+                Ok(Ok((answers, timings))) => {
+                    let (answer, time) = match n {
+                        1 => (answers.part1, timings.part1),
+                        _ => (answers.part2, timings.part2),
+                    };
+                    Ok((answer.unwrap(), time.unwrap()))
+                }
+            };
+
+            Record {
+                day: day.day,
+                part: n,
+                input: name.to_string(),
+                result,
+            }
+        })
+        .collect()
+}
+
+/// Records of the selected parts of `day` failing with `error`.
+pub fn failed(day: u32, name: &str, part: Part, error: Error) -> Vec<Record> {
+    parts(part)
+        .map(|(n, _)| Record {
+            day,
+            part: n,
+            input: name.to_string(),
+            result: Err(error.clone()),
+        })
+        .collect()
+}
+
+fn answer_type(answer: &Answer) -> &'static str {
+    match answer {
+        Answer::Int(_) => "int",
+        Answer::UInt(_) => "uint",
+        Answer::None => "none",
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from("\"");
+
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+
+    json + "\""
+}
+
+/// Array of one object per record. Failed parts have a `null` answer and an
+/// `error` object with `kind` and `message`.
+pub fn to_json(records: &[Record]) -> String {
+    let mut entries = vec![];
+
+    for record in records {
+        let (answer, kind, elapsed, error) = match &record.result {
+            Ok((Answer::None, elapsed)) => (
+                "null".to_string(),
+                json_string("none"),
+                elapsed.as_nanos().to_string(),
+                "null".to_string(),
+            ),
+            Ok((answer, elapsed)) => (
+                answer.to_string(),
+                json_string(answer_type(answer)),
+                elapsed.as_nanos().to_string(),
+                "null".to_string(),
+            ),
+            Err(error) => (
+                "null".to_string(),
+                "null".to_string(),
+                "null".to_string(),
+                format!(
+                    "{{\"kind\": {}, \"message\": {}}}",
+                    json_string(error.kind.name()),
+                    json_string(&error.message)
+                ),
+            ),
+        };
+
+        entries.push(format!(
+            "  {{\"day\": {}, \"part\": {}, \"input\": {}, \"answer\": {answer}, \
+             \"type\": {kind}, \"elapsed_ns\": {elapsed}, \"error\": {error}}}",
+            record.day,
+            record.part,
+            json_string(&record.input)
+        ));
+    }
+
+    format!("[\n{}\n]\n", entries.join(",\n"))
+}
+
+/// Header line and one line per record. The answer, type and time of failed
+/// parts are empty, as are the error columns of solved parts.
+pub fn to_tsv(records: &[Record]) -> String {
+    let mut tsv = String::from("day\tpart\tinput\tanswer\ttype\telapsed_ns\terror\tmessage\n");
+    let clean = |s: &str| s.replace(['\t', '\n'], " ");
+
+    for record in records {
+        let (answer, kind, elapsed, error, message) = match &record.result {
+            Ok((answer, elapsed)) => (
+                answer.to_string(),
+                answer_type(answer),
+                elapsed.as_nanos().to_string(),
+                "",
+                String::new(),
+            ),
+            Err(error) => (
+                String::new(),
+                "",
+                String::new(),
+                error.kind.name(),
+                clean(&error.message),
+            ),
+        };
+
+        writeln!(
+            tsv,
+            "{}\t{}\t{}\t{answer}\t{kind}\t{elapsed}\t{error}\t{message}",
+            record.day,
+            record.part,
+            clean(&record.input)
+        )
+        .unwrap();
+    }
+
+    tsv
+}
+
+#[test]
+fn test_output() {
+    let mut records = solve(
+        crate::day(1).unwrap(),
+        "1abc2\n",
+        "example",
+        Part::Both,
+        None,
+    );
+    records.extend(failed(
+        2,
+        "input02.txt",
+        Part::Two,
+        Error {
+            kind: ErrorKind::Input,
+            message: "no \"such\" file".to_string(),
+        },
+    ));
+    // wires without a cut of 3, part 1 panics
+    records.extend(solve(
+        crate::day(25).unwrap(),
+        "a: b\n",
+        "-",
+        Part::Both,
+        None,
+    ));
+    records.extend(solve(crate::day(7).unwrap(), "x\n", "-", Part::One, None));
+
+    assert_eq!(records.len(), 6);
+    assert_eq!(records[0].result.as_ref().unwrap().0, Answer::UInt(12));
+    assert_eq!(
+        records[3].result.as_ref().unwrap_err().kind,
+        ErrorKind::Panic
+    );
+    assert_eq!(records[4].result.as_ref().unwrap().0, Answer::None);
+    assert_eq!(
+        records[5].result.as_ref().unwrap_err().kind,
+        ErrorKind::Parse
+    );
+
+    for record in &mut records {
+        if let Ok((_, elapsed)) = &mut record.result {
+            *elapsed = Duration::from_nanos(7);
+        }
+    }
+
+    let lines: Vec<_> = to_json(&records).lines().map(str::to_string).collect();
+    assert_eq!(lines.len(), 8);
+    assert_eq!(
+        lines[1],
+        "  {\"day\": 1, \"part\": 1, \"input\": \"example\", \"answer\": 12, \
+         \"type\": \"uint\", \"elapsed_ns\": 7, \"error\": null},"
+    );
+    assert_eq!(
+        lines[3],
+        "  {\"day\": 2, \"part\": 2, \"input\": \"input02.txt\", \"answer\": null, \
+         \"type\": null, \"elapsed_ns\": null, \
+         \"error\": {\"kind\": \"input\", \"message\": \"no \\\"such\\\" file\"}},"
+    );
+    assert!(lines[4].contains("\"error\": {\"kind\": \"panic\""));
+
+    let tsv = to_tsv(&records);
+    let lines: Vec<_> = tsv.lines().collect();
+    assert_eq!(lines[2], "1\t2\texample\t12\tuint\t7\t\t");
+    assert_eq!(lines[3], "2\t2\tinput02.txt\t\t\t\tinput\tno \"such\" file");
+}
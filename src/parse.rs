@@ -0,0 +1,403 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::grid::Grid;
+
+/// Error of a day's parser pointing at the offending line and column (both
+/// starting at 1).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: Option<String>,
+}
+
+impl ParseError {
+    /// Creates an error at the position of `token`, which has to be a slice of
+    /// `input`. An empty `token` reports that something is missing.
+    pub fn at(input: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&o| o <= input.len())
+            .expect("token is not part of the input");
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |n| n + 1);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            found: token
+                .lines()
+                .next()
+                .filter(|t| !t.is_empty())
+                .map(str::to_string),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+
+        match &self.found {
+            Some(found) => write!(f, ", found `{found}`"),
+            None => write!(f, ", found nothing"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// `input` with `\n` line endings, without trailing spaces and tabs on its
+/// lines and ending in a single newline, as inputs saved on Windows or with a
+/// trailing blank line would otherwise break the block and grid parsers. Lines
+/// and columns stay the same, so parse errors still point into the original.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let lines: Vec<_> = input
+        .trim_end()
+        .split('\n')
+        .map(|line| line.trim_end_matches(['\r', ' ', '\t']))
+        .collect();
+
+    let normalized = match lines.as_slice() {
+        [""] => String::new(),
+        _ => lines.join("\n") + "\n",
+    };
+
+    match normalized == input {
+        true => Cow::Borrowed(input),
+        false => Cow::Owned(normalized),
+    }
+}
+
+/// Position in the input of a day. The methods consume what they recognize
+/// from the front and report errors at the current position. Token parsers
+/// skip spaces (but not newlines) in front of the token. Combinators take
+/// parsers as `FnMut(&mut Cursor) -> Result<T, ParseError>`, so methods like
+/// [`Cursor::uint`] can be passed directly.
+#[derive(Clone, Copy, Debug)]
+pub struct Cursor<'a> {
+    input: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Cursor { input, rest: input }
+    }
+
+    /// The unparsed remainder.
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    fn sub(&self, text: &'a str) -> Cursor<'a> {
+        Cursor {
+            input: self.input,
+            rest: text,
+        }
+    }
+
+    fn take_all(&mut self) -> &'a str {
+        let rest = self.rest;
+        self.rest = &rest[rest.len()..];
+        rest
+    }
+
+    /// Error at the next token.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let rest = self.rest.trim_start_matches([' ', '\t']);
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+
+        ParseError::at(self.input, &rest[..end], expected)
+    }
+
+    /// Error at `token`, a slice that was returned by this cursor.
+    pub fn error_at(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at(self.input, token, expected)
+    }
+
+    fn skip_spaces(&mut self) {
+        self.rest = self.rest.trim_start_matches([' ', '\t']);
+    }
+
+    /// Consumes the longest prefix of characters matching `pred`.
+    pub fn take_while<P>(&mut self, pred: P) -> &'a str
+    where
+        P: Fn(char) -> bool,
+    {
+        let end = self.rest.find(|c| !pred(c)).unwrap_or(self.rest.len());
+        let (token, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        token
+    }
+
+    /// A non-empty run of characters matching `pred`.
+    pub fn token<P>(&mut self, pred: P, expected: &str) -> Result<&'a str, ParseError>
+    where
+        P: Fn(char) -> bool,
+    {
+        self.skip_spaces();
+
+        match self.take_while(pred) {
+            "" => Err(self.error(expected)),
+            token => Ok(token),
+        }
+    }
+
+    /// Consumes `tag` or fails.
+    pub fn tag(&mut self, tag: &str) -> Result<(), ParseError> {
+        if self.try_tag(tag) {
+            Ok(())
+        } else if tag == "\n" {
+            Err(self.error("end of line"))
+        } else {
+            Err(self.error(format!("`{tag}`")))
+        }
+    }
+
+    /// Consumes `tag` if it comes next.
+    pub fn try_tag(&mut self, tag: &str) -> bool {
+        self.skip_spaces();
+
+        match self.rest.strip_prefix(tag) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn number<T: FromStr>(&mut self, len: usize) -> Result<T, ParseError> {
+        let token = &self.rest[..len];
+        let n = token.parse().map_err(|_| self.error("number"))?;
+        self.rest = &self.rest[len..];
+
+        Ok(n)
+    }
+
+    /// Unsigned decimal integer.
+    pub fn uint<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.skip_spaces();
+
+        let len = self.rest.find(|c: char| !c.is_ascii_digit());
+        self.number(len.unwrap_or(self.rest.len()))
+    }
+
+    /// Decimal integer with an optional sign.
+    pub fn int<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.skip_spaces();
+
+        let sign = usize::from(self.rest.starts_with(['-', '+']));
+        let len = self.rest[sign..].find(|c: char| !c.is_ascii_digit());
+        self.number(sign + len.unwrap_or(self.rest.len() - sign))
+    }
+
+    /// Alphanumeric name, e.g. of a node or a module.
+    pub fn ident(&mut self) -> Result<&'a str, ParseError> {
+        self.token(|c| c.is_alphanumeric() || c == '_', "name")
+    }
+
+    /// Anything up to the next whitespace.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        self.token(|c| !c.is_whitespace(), "word")
+    }
+
+    /// Succeeds if only whitespace is left.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        if self.rest.trim().is_empty() {
+            self.take_all();
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+
+    /// Applies `item` as often as it succeeds.
+    pub fn many<T, F>(&mut self, mut item: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+    {
+        let mut items = vec![];
+
+        loop {
+            let mut cursor = *self;
+            match item(&mut cursor) {
+                Ok(t) if cursor.rest.len() < self.rest.len() => {
+                    items.push(t);
+                    *self = cursor;
+                }
+                _ => return Ok(items),
+            }
+        }
+    }
+
+    /// One or more `item`s separated by `sep`.
+    pub fn separated<T, F>(&mut self, sep: &str, mut item: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+    {
+        let mut items = vec![item(self)?];
+
+        while self.try_tag(sep) {
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    /// Records like `x=787,m=2655`: `key kv_sep value` pairs separated by
+    /// `pair_sep`.
+    pub fn key_values<T, F>(
+        &mut self,
+        pair_sep: &str,
+        kv_sep: &str,
+        mut value: F,
+    ) -> Result<Vec<(&'a str, T)>, ParseError>
+    where
+        F: FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+    {
+        self.separated(pair_sep, |c| {
+            let key = c.ident()?;
+            c.tag(kv_sep)?;
+            Ok((key, value(c)?))
+        })
+    }
+
+    /// Parses every remaining line with `item`, which has to consume the
+    /// whole line.
+    pub fn lines<T, F>(&mut self, mut item: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+    {
+        let rest = self.take_all();
+
+        rest.lines()
+            .map(|line| {
+                let mut cursor = self.sub(line);
+                let t = item(&mut cursor)?;
+                cursor.end()?;
+                Ok(t)
+            })
+            .collect()
+    }
+
+    /// Consumes the lines up to the next blank line and returns a cursor
+    /// over them.
+    pub fn block(&mut self) -> Cursor<'a> {
+        let (block, rest) = match self.rest.split_once("\n\n") {
+            Some(split) => split,
+            None => (self.rest, &self.rest[self.rest.len()..]),
+        };
+        self.rest = rest.trim_start_matches('\n');
+
+        self.sub(block)
+    }
+
+    /// Parses every remaining block of lines with `item`, which has to
+    /// consume the whole block.
+    pub fn blocks<T, F>(&mut self, mut item: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+    {
+        let mut items = vec![];
+
+        while !self.rest.trim().is_empty() {
+            let mut block = self.block();
+            items.push(item(&mut block)?);
+            block.end()?;
+        }
+        self.take_all();
+
+        Ok(items)
+    }
+
+    /// Parses the rest as a [`Grid`], see [`Grid::parse_with`].
+    pub fn grid<T, F>(&mut self, expected: &str, tile: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let grid = Grid::parse_with(self.input, self.rest, expected, tile)?;
+        self.take_all();
+
+        Ok(grid)
+    }
+}
+
+#[test]
+fn test_parse_error() {
+    let input = "1 2\n3 x4\n";
+    let mut cursor = Cursor::new(input);
+    cursor.tag("1").unwrap();
+    cursor.uint::<i64>().unwrap();
+    cursor.tag("\n").unwrap();
+    cursor.uint::<i64>().unwrap();
+    let err = cursor.uint::<i64>().unwrap_err();
+
+    assert_eq!((err.line, err.column), (2, 3));
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 3: expected number, found `x4`"
+    );
+
+    let mut cursor = Cursor::new(input);
+    let err = cursor.lines(|c| c.many(Cursor::uint::<i64>)).unwrap_err();
+    assert_eq!(
+        (err.line, err.column, err.expected.as_str()),
+        (2, 3, "end of line")
+    );
+
+    let err = Cursor::new("- 5").int::<i64>().unwrap_err();
+    assert_eq!((err.line, err.column, err.found), (1, 1, Some("-".into())));
+}
+
+#[test]
+fn test_cursor() {
+    let input = "a: 1, -2\nb: 3\n\n{x=7,m=8}\n";
+    let mut cursor = Cursor::new(input);
+
+    let lists = cursor.block().lines(|c| {
+        let name = c.ident()?;
+        c.tag(":")?;
+        Ok((name, c.separated(",", Cursor::int::<i64>)?))
+    });
+    assert_eq!(lists, Ok(vec![("a", vec![1, -2]), ("b", vec![3])]));
+
+    let mut record = cursor.block();
+    record.tag("{").unwrap();
+    let values = record.key_values(",", "=", Cursor::uint::<u8>).unwrap();
+    record.tag("}").unwrap();
+    assert_eq!(values, [("x", 7), ("m", 8)]);
+    assert!(record.end().is_ok());
+    assert!(cursor.end().is_ok());
+
+    let err = Cursor::new("1\n\nab")
+        .blocks(Cursor::uint::<u8>)
+        .unwrap_err();
+    assert!(err.to_string().starts_with("line 3, column 1"));
+}
+
+#[test]
+fn test_normalize() {
+    assert!(matches!(
+        normalize("a\n\nb c\n"),
+        Cow::Borrowed("a\n\nb c\n")
+    ));
+    assert_eq!(normalize("a \r\n\r\nb c\t\r\n \r\n\n"), "a\n\nb c\n");
+    assert_eq!(normalize("a\nb"), "a\nb\n");
+    assert_eq!(normalize(" \n\n"), "");
+
+    let err = Cursor::new(&normalize("1 \r\n2x\r\n"))
+        .lines(Cursor::uint::<u8>)
+        .unwrap_err();
+    assert_eq!((err.line, err.column), (2, 2));
+}
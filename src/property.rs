@@ -0,0 +1,190 @@
+use std::fmt;
+
+use rand::rngs::StdRng;
+
+use crate::cancel;
+use crate::generate;
+
+/// A random input on which a property does not hold, shrunk as far as
+/// possible.
+#[derive(Debug)]
+pub struct Failure<T> {
+    pub input: T,
+    pub message: String,
+    /// Seed of the case that failed first.
+    pub seed: u64,
+    /// Number of successful shrinking steps.
+    pub shrinks: usize,
+}
+
+impl<T: fmt::Debug> fmt::Display for Failure<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "property failed for seed {} after {} shrinks: {}\ninput: {:#?}",
+            self.seed, self.shrinks, self.message, self.input
+        )
+    }
+}
+
+fn run<T, P>(property: &P, input: &T) -> Result<(), String>
+where
+    P: Fn(&T) -> Result<(), String>,
+{
+    cancel::isolate(None, || property(input)).unwrap_or_else(|abort| Err(abort.to_string()))
+}
+
+/// Checks `property` on `cases` inputs from `generate`, each drawn from its
+/// own seed. A failing input is shrunk by repeatedly replacing it with the
+/// first candidate from `shrink` that still fails; panics count as failures.
+///
+/// Inputs that `shrink` takes outside the assumptions of a solver should
+/// simply pass the property.
+pub fn check<T, G, S, P>(
+    cases: u64,
+    mut generate: G,
+    shrink: S,
+    property: P,
+) -> Result<(), Failure<T>>
+where
+    G: FnMut(&mut StdRng) -> T,
+    S: Fn(&T) -> Vec<T>,
+    P: Fn(&T) -> Result<(), String>,
+{
+    for seed in 0..cases {
+        let input = generate(&mut generate::rng(seed));
+        let Err(message) = run(&property, &input) else {
+            continue;
+        };
+
+        let mut failure = Failure {
+            input,
+            message,
+            seed,
+            shrinks: 0,
+        };
+
+        'shrink: loop {
+            for candidate in shrink(&failure.input) {
+                if let Err(message) = run(&property, &candidate) {
+                    failure.input = candidate;
+                    failure.message = message;
+                    failure.shrinks += 1;
+                    continue 'shrink;
+                }
+            }
+
+            return Err(failure);
+        }
+    }
+
+    Ok(())
+}
+
+/// Compares a result with the one of a reference solver, for use in a
+/// property.
+pub fn compare<A: PartialEq + fmt::Debug>(
+    what: &str,
+    actual: A,
+    reference: A,
+) -> Result<(), String> {
+    if actual == reference {
+        Ok(())
+    } else {
+        Err(format!(
+            "{what} is {actual:?}, the reference gives {reference:?}"
+        ))
+    }
+}
+
+/// Shrinks `text` by removing one line.
+pub fn remove_lines(text: &str) -> Vec<String> {
+    let lines: Vec<_> = text.lines().collect();
+
+    (0..lines.len())
+        .map(|i| {
+            let rest = [&lines[..i], &lines[i + 1..]].concat();
+            rest.iter().map(|line| format!("{line}\n")).collect()
+        })
+        .collect()
+}
+
+/// Shrinks a grid in `text` by removing one column from every line.
+pub fn remove_columns(text: &str) -> Vec<String> {
+    let width = text.lines().map(|line| line.len()).max().unwrap_or(0);
+
+    (0..width)
+        .map(|x| {
+            text.lines()
+                .map(|line| {
+                    let mut line = line.to_string();
+                    if x < line.len() {
+                        line.remove(x);
+                    }
+                    line + "\n"
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Shrinks `text` by replacing one number with 0, its half or its
+/// predecessor.
+pub fn smaller_numbers(text: &str) -> Vec<String> {
+    let mut candidates = vec![];
+    let mut start = None;
+
+    for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        match (c.is_ascii_digit(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(from)) => {
+                start = None;
+                let Ok(n) = text[from..i].parse::<u64>() else {
+                    continue;
+                };
+
+                let mut smaller = vec![0, n / 2, n.saturating_sub(1)];
+                smaller.dedup();
+                for m in smaller.into_iter().filter(|&m| m < n) {
+                    candidates.push(format!("{}{m}{}", &text[..from], &text[i..]));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    candidates
+}
+
+#[test]
+fn test_property() {
+    use rand::Rng;
+
+    let lines = |rng: &mut StdRng| {
+        let numbers: Vec<_> = (0..10).map(|_| rng.gen_range(0..1000)).collect();
+        numbers.iter().map(|n| format!("{n}\n")).collect::<String>()
+    };
+    let shrink = |text: &String| [remove_lines(text), smaller_numbers(text)].concat();
+    let small = |limit| {
+        move |text: &String| match text.lines().find(|n| n.parse::<u64>().unwrap() >= limit) {
+            Some(n) => Err(format!("{n} is too large")),
+            None => Ok(()),
+        }
+    };
+
+    assert!(check(20, lines, shrink, small(1000)).is_ok());
+
+    // shrinks to a single line with the smallest failing number
+    let failure = check(20, lines, shrink, small(100)).unwrap_err();
+    assert_eq!(failure.input, "100\n");
+    assert_eq!(failure.message, "100 is too large");
+
+    let failure = check(5, lines, shrink, |_| panic!("boom")).unwrap_err();
+    assert_eq!((failure.input.as_str(), failure.seed), ("", 0));
+    assert_eq!(failure.message, "panicked: boom");
+
+    assert_eq!(
+        remove_columns("abc\ndef\n"),
+        ["bc\nef\n", "ac\ndf\n", "ab\nde\n"]
+    );
+}
@@ -0,0 +1,368 @@
+use std::fmt::Write;
+use std::str::FromStr;
+
+use crate::grid::{Grid, Point};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const GRAY: Color = Color::rgb(128, 128, 128);
+    pub const DARK_GRAY: Color = Color::rgb(48, 48, 48);
+    pub const RED: Color = Color::rgb(220, 50, 47);
+    pub const GREEN: Color = Color::rgb(64, 160, 43);
+    pub const BLUE: Color = Color::rgb(38, 139, 210);
+    pub const YELLOW: Color = Color::rgb(240, 200, 30);
+    pub const ORANGE: Color = Color::rgb(240, 130, 20);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b }
+    }
+
+    /// Linear interpolation between `self` at `t = 0` and `other` at `t = 1`.
+    pub fn mix(self, other: Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+
+        Color::rgb(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+        )
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// Image file formats written by [`Canvas::encode`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    #[default]
+    Png,
+    Svg,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Png => "png",
+            Format::Svg => "svg",
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "ppm" => Ok(Format::Ppm),
+            "png" => Ok(Format::Png),
+            "svg" => Ok(Format::Svg),
+            _ => Err(format!("unknown image format: {s}")),
+        }
+    }
+}
+
+/// Picture of a grid: one coloured square per tile, with paths through the
+/// tile centres drawn on top.
+#[derive(Clone, Debug)]
+pub struct Canvas {
+    cells: Grid<Color>,
+    paths: Vec<(Vec<Point>, Color)>,
+}
+
+impl Canvas {
+    pub fn new<T, F>(grid: &Grid<T>, color: F) -> Canvas
+    where
+        F: FnMut(&T) -> Color,
+    {
+        Canvas {
+            cells: grid.map(color),
+            paths: vec![],
+        }
+    }
+
+    /// Recolours a single tile, points outside of the grid are ignored.
+    pub fn fill(&mut self, p: Point, color: Color) {
+        if let Some(cell) = self.cells.get_mut(p) {
+            *cell = color;
+        }
+    }
+
+    /// Draws a line through the centres of `points`. Consecutive points need
+    /// not be adjacent.
+    pub fn path(&mut self, points: impl IntoIterator<Item = Point>, color: Color) {
+        self.paths.push((points.into_iter().collect(), color));
+    }
+
+    /// Rasterizes the canvas with `scale` pixels per tile side.
+    pub fn to_image(&self, scale: usize) -> Image {
+        let scale = scale.max(1);
+        let (width, height) = (self.cells.width() * scale, self.cells.height() * scale);
+        let mut image = Image {
+            width,
+            height,
+            pixels: vec![Color::BLACK; width * height],
+        };
+
+        for (p, &color) in self.cells.iter() {
+            let (x, y) = (p.x as usize * scale, p.y as usize * scale);
+            image.fill_rect(x as i64, y as i64, scale, color);
+        }
+
+        let thickness = (scale / 3).max(1);
+        let center = |p: Point| p * scale as i64 + Point::new(1, 1) * (scale as i64 / 2);
+
+        for (points, color) in &self.paths {
+            for (a, b) in points.iter().zip(points.iter().skip(1)) {
+                let (a, b) = (center(*a), center(*b));
+                let steps = (b.x - a.x).abs().max((b.y - a.y).abs()).max(1);
+
+                for i in 0..=steps {
+                    let x = a.x + (b.x - a.x) * i / steps - thickness as i64 / 2;
+                    let y = a.y + (b.y - a.y) * i / steps - thickness as i64 / 2;
+                    image.fill_rect(x, y, thickness, *color);
+                }
+            }
+        }
+
+        image
+    }
+
+    /// Vector version of [`Canvas::to_image`]. Runs of equally coloured
+    /// tiles share a single rectangle to keep the file small.
+    pub fn to_svg(&self, scale: usize) -> String {
+        let scale = scale.max(1);
+        let (width, height) = (self.cells.width() * scale, self.cells.height() * scale);
+        let mut svg = String::new();
+
+        writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">"
+        )
+        .unwrap();
+
+        for (y, row) in self.cells.rows().enumerate() {
+            let mut x = 0;
+            for run in row.chunk_by(|a, b| a == b) {
+                writeln!(
+                    svg,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{scale}\" fill=\"{}\"/>",
+                    x * scale,
+                    y * scale,
+                    run.len() * scale,
+                    run[0].hex()
+                )
+                .unwrap();
+                x += run.len();
+            }
+        }
+
+        for (points, color) in &self.paths {
+            let points: Vec<_> = points
+                .iter()
+                .map(|p| {
+                    let (x, y) = (p.x as f64 + 0.5, p.y as f64 + 0.5);
+                    format!("{},{}", x * scale as f64, y * scale as f64)
+                })
+                .collect();
+
+            writeln!(
+                svg,
+                "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" \
+                 stroke-linejoin=\"round\" stroke-linecap=\"round\"/>",
+                points.join(" "),
+                color.hex(),
+                (scale as f64 / 3.0).max(1.0)
+            )
+            .unwrap();
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// The file contents of the canvas in `format`.
+    pub fn encode(&self, format: Format, scale: usize) -> Vec<u8> {
+        match format {
+            Format::Ppm => self.to_image(scale).to_ppm(),
+            Format::Png => self.to_image(scale).to_png(),
+            Format::Svg => self.to_svg(scale).into_bytes(),
+        }
+    }
+}
+
+/// RGB raster image stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Color>,
+}
+
+impl Image {
+    /// Fills a `size` x `size` square, clipped to the image.
+    fn fill_rect(&mut self, x: i64, y: i64, size: usize, color: Color) {
+        let clip = |v: i64, max: usize| v.clamp(0, max as i64) as usize;
+        let (x0, x1) = (clip(x, self.width), clip(x + size as i64, self.width));
+        let (y0, y1) = (clip(y, self.height), clip(y + size as i64, self.height));
+
+        for y in y0..y1 {
+            self.pixels[y * self.width + x0..y * self.width + x1].fill(color);
+        }
+    }
+
+    fn rgb(&self) -> impl Iterator<Item = [u8; 3]> + '_ {
+        self.pixels.iter().map(|c| [c.r, c.g, c.b])
+    }
+
+    // https://netpbm.sourceforge.net/doc/ppm.html
+    /// Binary PPM (P6), the simplest format most image viewers understand.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend(self.rgb().flatten());
+        ppm
+    }
+
+    // https://www.w3.org/TR/png/
+    /// PNG with uncompressed (stored) deflate blocks, so no compression
+    /// library is needed at the cost of larger files.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut scanlines = Vec::with_capacity((self.width * 3 + 1) * self.height);
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            scanlines.push(0); // filter type None
+            scanlines.extend(row.iter().flat_map(|c| [c.r, c.g, c.b]));
+        }
+
+        let mut header = vec![];
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // bit depth 8, colour type RGB, deflate, adaptive filtering, no interlace
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut png = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+        png_chunk(&mut png, b"IHDR", &header);
+        png_chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+        png_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    png.extend(kind);
+    png.extend(data);
+
+    let crc = crc32(kind.iter().chain(data).copied());
+    png.extend(crc.to_be_bytes());
+}
+
+// https://www.rfc-editor.org/rfc/rfc1950 and rfc1951 section 3.2.4
+/// zlib stream of `data` in stored blocks of at most 65535 bytes.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+
+    if blocks.peek().is_none() {
+        zlib.extend([1, 0, 0, 0xff, 0xff]);
+    }
+
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+
+        zlib.push(last as u8);
+        zlib.extend(len.to_le_bytes());
+        zlib.extend((!len).to_le_bytes());
+        zlib.extend(block);
+    }
+
+    zlib.extend(adler32(data).to_be_bytes());
+    zlib
+}
+
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut n = 0;
+
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 == 1 {
+                0xedb88320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+    }
+
+    table
+};
+
+fn crc32(data: impl IntoIterator<Item = u8>) -> u32 {
+    !data.into_iter().fold(!0, |crc, byte| {
+        CRC_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let (mut a, mut b) = (1, 0);
+
+    for &byte in data {
+        a = (a + byte as u32) % MOD;
+        b = (b + a) % MOD;
+    }
+
+    b << 16 | a
+}
+
+#[test]
+fn test_render() {
+    assert_eq!(crc32(*b"123456789"), 0xcbf43926);
+    assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+
+    let grid = Grid::parse("#.\n.#\n").unwrap();
+    let mut canvas = Canvas::new(
+        &grid,
+        |&t| if t == '#' { Color::WHITE } else { Color::BLACK },
+    );
+    canvas.fill(Point::new(1, 0), Color::RED);
+    canvas.path([Point::new(0, 0), Point::new(0, 1)], Color::BLUE);
+
+    let image = canvas.to_image(3);
+    assert_eq!((image.width, image.height), (6, 6));
+    assert_eq!(image.pixels[0], Color::WHITE);
+    assert_eq!(image.pixels[6 + 1], Color::BLUE);
+    assert_eq!(image.pixels[3], Color::RED);
+    assert_eq!(image.pixels[6 * 5 + 5], Color::WHITE);
+
+    let ppm = image.to_ppm();
+    assert!(ppm.starts_with(b"P6\n6 6\n255\n"));
+    assert_eq!(ppm.len(), 11 + 6 * 6 * 3);
+
+    let png = image.to_png();
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x06\0\0\0\x06"));
+    assert!(png.ends_with(b"\0\0\0\0IEND\xae\x42\x60\x82"));
+
+    let svg = canvas.to_svg(3);
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains("<rect x=\"3\" y=\"0\" width=\"3\" height=\"3\" fill=\"#dc322f\"/>"));
+    assert!(svg.contains("<polyline points=\"1.5,1.5 1.5,4.5\""));
+}
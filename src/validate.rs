@@ -0,0 +1,52 @@
+use std::fmt;
+
+/// Property of the official inputs that a solution relies on, see
+/// [`Solution::validate`](crate::Solution::validate).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Assumption {
+    pub description: &'static str,
+    /// Why the assumption does not hold for an input.
+    pub result: Result<(), String>,
+}
+
+impl Assumption {
+    pub fn new(description: &'static str, result: Result<(), String>) -> Assumption {
+        Assumption {
+            description,
+            result,
+        }
+    }
+
+    /// An assumption whose violation needs no further explanation.
+    pub fn check(description: &'static str, holds: bool) -> Assumption {
+        Assumption::new(description, if holds { Ok(()) } else { Err(String::new()) })
+    }
+
+    pub fn holds(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+impl fmt::Display for Assumption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.result {
+            Ok(()) => write!(f, "ok      {}", self.description),
+            Err(reason) if reason.is_empty() => write!(f, "FAILED  {}", self.description),
+            Err(reason) => write!(f, "FAILED  {}: {reason}", self.description),
+        }
+    }
+}
+
+#[test]
+fn test_validate() {
+    let holds = Assumption::check("the map is square", true);
+    assert!(holds.holds());
+    assert_eq!(holds.to_string(), "ok      the map is square");
+
+    let fails = Assumption::check("the map is square", false);
+    assert_eq!(fails.to_string(), "FAILED  the map is square");
+
+    let fails = Assumption::new("S is in the centre", Err("S is at 1,2".to_string()));
+    assert!(!fails.holds());
+    assert_eq!(fails.to_string(), "FAILED  S is in the centre: S is at 1,2");
+}
@@ -0,0 +1,221 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::{cancel, input_file, Part};
+
+/// One line of the answers file: `day part input answer`, where `input` is
+/// relative to the `input/` directory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Expected {
+    pub day: u32,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+}
+
+pub fn parse_answers(text: &str) -> Result<Vec<Expected>, String> {
+    let mut answers = vec![];
+
+    for (n, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let error = || format!("line {}: expected `day part input answer`", n + 1);
+        let fields: Vec<_> = line.split_ascii_whitespace().collect();
+
+        let [day, part, input, answer] = fields[..] else {
+            return Err(error());
+        };
+
+        answers.push(Expected {
+            day: day.parse().map_err(|_| error())?,
+            part: match part {
+                "1" => 1,
+                "2" => 2,
+                _ => return Err(error()),
+            },
+            input: input.to_string(),
+            answer: answer.to_string(),
+        });
+    }
+
+    Ok(answers)
+}
+
+/// Every registered input: those of `dir/answers.txt` and of the answers
+/// file of each user directory `dir/<name>/`, whose inputs are relative to
+/// that directory. Users are listed by name.
+pub fn registered(dir: &Path) -> Result<Vec<Expected>, String> {
+    let read = |path: &Path| {
+        fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| parse_answers(&text))
+            .map_err(|e| format!("{}: {e}", path.display()))
+    };
+
+    let mut expected = read(&dir.join("answers.txt"))?;
+
+    let mut users = vec![];
+    for entry in fs::read_dir(dir).map_err(|e| format!("{}: {e}", dir.display()))? {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        if path.join("answers.txt").is_file() {
+            users.push(path);
+        }
+    }
+    users.sort();
+
+    for path in users {
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        for mut e in read(&path.join("answers.txt"))? {
+            e.input = format!("{name}/{}", e.input);
+            expected.push(e);
+        }
+    }
+
+    Ok(expected)
+}
+
+/// Distinct inputs of `day` in `expected`, in order.
+pub fn inputs(expected: &[Expected], day: u32) -> Vec<&str> {
+    let mut inputs = vec![];
+
+    for e in expected.iter().filter(|e| e.day == day) {
+        if !inputs.contains(&e.input.as_str()) {
+            inputs.push(e.input.as_str());
+        }
+    }
+
+    inputs
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Mismatch(String),
+    Fail(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct Outcome {
+    pub expected: Expected,
+    pub status: Status,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Expected {
+            day,
+            part,
+            input,
+            answer,
+        } = &self.expected;
+
+        write!(f, "day {day:02} part {part} {input}: ")?;
+
+        match &self.status {
+            Status::Pass => write!(f, "pass"),
+            Status::Mismatch(actual) => {
+                write!(f, "MISMATCH expected {answer}, got {actual}")
+            }
+            Status::Fail(reason) => write!(f, "FAIL {reason}"),
+        }
+    }
+}
+
+/// Solves every input of `expected` once and compares the answers. A panic
+/// or a search running longer than `timeout` fails the answers of the input.
+pub fn verify(expected: &[Expected], timeout: Option<Duration>) -> Vec<Outcome> {
+    let mut by_input = BTreeMap::<_, Vec<_>>::new();
+
+    for e in expected {
+        by_input.entry((e.day, &e.input)).or_default().push(e);
+    }
+
+    let mut outcomes = vec![];
+
+    for ((day, input), expected) in by_input {
+        let part = match (
+            expected.iter().any(|e| e.part == 1),
+            expected.iter().any(|e| e.part == 2),
+        ) {
+            (true, false) => Part::One,
+            (false, true) => Part::Two,
+            _ => Part::Both,
+        };
+
+        let answers = match (crate::day(day), fs::read_to_string(input_file(input))) {
+            (None, _) => Err(format!("day {day} is not solved")),
+            (_, Err(err)) => Err(format!("cannot read {input}: {err}")),
+            (Some(solution), Ok(text)) => {
+                match cancel::isolate(timeout, || solution.solve(&text, part)) {
+                    Ok(answers) => answers.map_err(|e| format!("cannot parse {input}: {e}")),
+                    Err(abort) => Err(abort.to_string()),
+                }
+            }
+        };
+
+        for e in expected {
+            let status = match &answers {
+                Err(reason) => Status::Fail(reason.clone()),
+                Ok(answers) => {
+                    let actual = if e.part == 1 {
+                        &answers.part1
+                    } else {
+                        &answers.part2
+                    };
+
+                    match actual {
+                        Some(a) if a.to_string() == e.answer => Status::Pass,
+                        Some(a) => Status::Mismatch(a.to_string()),
+                        None => Status::Fail("part was not solved".to_string()),
+                    }
+                }
+            };
+
+            outcomes.push(Outcome {
+                expected: e.clone(),
+                status,
+            });
+        }
+    }
+
+    outcomes
+}
+
+#[test]
+fn test_parse_answers() {
+    let answers = parse_answers(
+        "\
+# day part input answer
+01 1 input01.txt 142
+
+1 2 input01.txt 281",
+    )
+    .unwrap();
+
+    assert_eq!(answers.len(), 2);
+    assert_eq!(
+        answers[1],
+        Expected {
+            day: 1,
+            part: 2,
+            input: "input01.txt".to_string(),
+            answer: "281".to_string(),
+        }
+    );
+
+    assert!(parse_answers("01 3 input01.txt 1").is_err());
+    assert!(parse_answers("01 1 input01.txt").is_err());
+
+    assert_eq!(inputs(&answers, 1), ["input01.txt"]);
+    assert!(inputs(&answers, 2).is_empty());
+
+    let registered = registered(&input_file("")).unwrap();
+    assert_eq!(inputs(&registered, 1)[0], "input01.txt");
+    assert!(registered.iter().all(|e| input_file(&e.input).is_file()));
+}
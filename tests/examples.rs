@@ -0,0 +1,154 @@
+use std::fs;
+use std::path::Path;
+
+use aoc_2023::{cancel, Answer, Part};
+
+/// Parameters and expected answers of an `examples/dayNN/NAME.answers` file,
+/// one `key = value` per line. `part1` and `part2` are the answers, the
+/// other keys are config parameters of the day.
+struct Expected {
+    params: Vec<(String, String)>,
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+fn parse_answers(text: &str) -> Result<Expected, String> {
+    let mut expected = Expected {
+        params: vec![],
+        part1: None,
+        part2: None,
+    };
+
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("expected `key = value`: {line}"))?;
+        let (key, value) = (key.trim(), value.trim().to_string());
+        match key {
+            "part1" => expected.part1 = Some(value),
+            "part2" => expected.part2 = Some(value),
+            _ => expected.params.push((key.to_string(), value)),
+        }
+    }
+
+    Ok(expected)
+}
+
+fn check(day: u32, input: &str, expected: &Expected) -> Result<(), String> {
+    let part = match (&expected.part1, &expected.part2) {
+        (Some(_), Some(_)) => Part::Both,
+        (Some(_), None) => Part::One,
+        (None, Some(_)) => Part::Two,
+        (None, None) => return Err("no answers".to_string()),
+    };
+    let params: Vec<_> = expected
+        .params
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect();
+
+    let solution = aoc_2023::day(day).ok_or_else(|| format!("no day {day}"))?;
+    let answers = cancel::isolate(None, || solution.solve_with(input, part, &params))
+        .map_err(|abort| abort.to_string())??;
+
+    let mut errors = vec![];
+    for (n, answer, expected) in [
+        (1, answers.part1, &expected.part1),
+        (2, answers.part2, &expected.part2),
+    ] {
+        if let (Some(answer), Some(expected)) = (answer.as_ref().map(Answer::to_string), expected) {
+            if answer != *expected {
+                errors.push(format!("part{n} = {answer}, expected {expected}"));
+            }
+        }
+    }
+
+    match errors.is_empty() {
+        true => Ok(()),
+        false => Err(errors.join(", ")),
+    }
+}
+
+/// `input` with CRLF line endings, trailing spaces on every other line and
+/// trailing blank lines.
+fn mangle(input: &str) -> String {
+    let lines: Vec<_> = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| match i % 2 {
+            0 => format!("{line}  \t"),
+            _ => line.to_string(),
+        })
+        .collect();
+
+    lines.join("\r\n") + "\r\n \r\n\r\n"
+}
+
+/// Every `examples/dayNN/NAME.txt` input against its `NAME.answers` file.
+#[test]
+fn test_examples() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    let mut days: Vec<_> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    days.sort();
+
+    let mut failures = vec![];
+    let mut covered = vec![];
+
+    for day_dir in days {
+        let name = day_dir.file_name().unwrap().to_string_lossy().into_owned();
+        let Some(day) = name.strip_prefix("day").and_then(|d| d.parse().ok()) else {
+            failures.push(format!("{name}: not a dayNN directory"));
+            continue;
+        };
+
+        let mut inputs: Vec<_> = fs::read_dir(&day_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+            .collect();
+        inputs.sort();
+
+        for path in inputs {
+            let fixture = format!("{name}/{}", path.file_name().unwrap().to_string_lossy());
+            let input = fs::read_to_string(&path).unwrap();
+            let expected = match fs::read_to_string(path.with_extension("answers"))
+                .map_err(|err| format!("no answers: {err}"))
+                .and_then(|text| parse_answers(&text))
+            {
+                Ok(expected) => expected,
+                Err(err) => {
+                    failures.push(format!("{fixture}: {err}"));
+                    continue;
+                }
+            };
+
+            match check(day, &input, &expected) {
+                Ok(()) => covered.push(day),
+                Err(err) => failures.push(format!("{fixture}: {err}")),
+            }
+
+            // the same answers for the input as saved on Windows or by a
+            // sloppy editor
+            if let Err(err) = check(day, &mangle(&input), &expected) {
+                failures.push(format!(
+                    "{fixture} with CRLF and trailing whitespace: {err}"
+                ));
+            }
+        }
+    }
+
+    for day in 1..=25 {
+        if !covered.contains(&day) {
+            failures.push(format!("day{day:02}: no passing example"));
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}